@@ -31,6 +31,45 @@ impl Display for Color {
     }
 }
 
+impl Color {
+    /// Render this color as ANSI escape sequence, exactly like `git` would, so that it can be written to a terminal.
+    ///
+    /// An empty color, which means "no color", is rendered as empty string. Note that `git` treats `normal` as
+    /// absence of a color, so it doesn't produce any output either.
+    pub fn to_ansi_escape_sequence(&self) -> String {
+        let has_reset = self.attributes.contains(Attribute::RESET);
+        let codes = self.attributes.sgr_codes();
+        let fg = self.foreground.filter(|c| *c != Name::Normal);
+        let bg = self.background.filter(|c| *c != Name::Normal);
+        if !has_reset && codes.is_empty() && fg.is_none() && bg.is_none() {
+            return String::new();
+        }
+
+        let mut out = String::from("\x1b[");
+        let mut needs_separator = has_reset;
+        let mut separate = |out: &mut String| {
+            if needs_separator {
+                out.push(';');
+            }
+            needs_separator = true;
+        };
+        for code in codes {
+            separate(&mut out);
+            out.push_str(&code.to_string());
+        }
+        if let Some(fg) = fg {
+            separate(&mut out);
+            fg.write_sgr_parameters(&mut out, false);
+        }
+        if let Some(bg) = bg {
+            separate(&mut out);
+            bg.write_sgr_parameters(&mut out, true);
+        }
+        out.push('m');
+        out
+    }
+}
+
 fn color_err(input: impl Into<BString>) -> Error {
     Error::new(
         "Colors are specific color values and their attributes, like 'brightred', or 'blue'",
@@ -153,6 +192,46 @@ impl Display for Name {
     }
 }
 
+impl Name {
+    /// Write the SGR parameters to select this color into `out`, as background color if `background` is `true`.
+    fn write_sgr_parameters(self, out: &mut String, background: bool) {
+        use std::fmt::Write;
+        let (base, bright_base, extended) = if background { (40, 100, 48) } else { (30, 90, 38) };
+        let ansi = match self {
+            Self::Normal => unreachable!("BUG: normal colors are never written"),
+            Self::Default => None,
+            Self::Black => Some(0),
+            Self::Red => Some(1),
+            Self::Green => Some(2),
+            Self::Yellow => Some(3),
+            Self::Blue => Some(4),
+            Self::Magenta => Some(5),
+            Self::Cyan => Some(6),
+            Self::White => Some(7),
+            Self::BrightBlack => Some(8),
+            Self::BrightRed => Some(9),
+            Self::BrightGreen => Some(10),
+            Self::BrightYellow => Some(11),
+            Self::BrightBlue => Some(12),
+            Self::BrightMagenta => Some(13),
+            Self::BrightCyan => Some(14),
+            Self::BrightWhite => Some(15),
+            Self::Ansi(num) => Some(num),
+            Self::Rgb(r, g, b) => {
+                write!(out, "{extended};2;{r};{g};{b}").expect("writing to a string cannot fail");
+                return;
+            }
+        };
+        match ansi {
+            None => write!(out, "{}", base + 9),
+            Some(num @ 0..=7) => write!(out, "{}", base + num),
+            Some(num @ 8..=15) => write!(out, "{}", bright_base + num - 8),
+            Some(num) => write!(out, "{extended};5;{num}"),
+        }
+        .expect("writing to a string cannot fail")
+    }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for Name {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -257,6 +336,34 @@ bitflags::bitflags! {
     }
 }
 
+impl Attribute {
+    /// Return the SGR parameters of all attributes except for [`RESET`](Attribute::RESET), sorted and deduplicated.
+    fn sgr_codes(&self) -> Vec<u8> {
+        let mut codes: Vec<_> = [
+            (Attribute::BOLD, 1),
+            (Attribute::DIM, 2),
+            (Attribute::ITALIC, 3),
+            (Attribute::UL, 4),
+            (Attribute::BLINK, 5),
+            (Attribute::REVERSE, 7),
+            (Attribute::STRIKE, 9),
+            (Attribute::NO_BOLD, 22),
+            (Attribute::NO_DIM, 22),
+            (Attribute::NO_ITALIC, 23),
+            (Attribute::NO_UL, 24),
+            (Attribute::NO_BLINK, 25),
+            (Attribute::NO_REVERSE, 27),
+            (Attribute::NO_STRIKE, 29),
+        ]
+        .into_iter()
+        .filter_map(|(attr, code)| self.contains(attr).then_some(code))
+        .collect();
+        codes.sort_unstable();
+        codes.dedup();
+        codes
+    }
+}
+
 impl Display for Attribute {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut write_space = None;
@@ -4,6 +4,29 @@ use bstr::{BStr, BString};
 
 use crate::{Error, Integer};
 
+/// Instantiation
+impl Integer {
+    /// Create a new instance representing `value` with the largest [suffix][Suffix] that can express it exactly,
+    /// which is the most compact way of writing it into a configuration file.
+    ///
+    /// For example, `1024` is represented as `1k` and `1536` as `1536`, without suffix, as neither unit fits exactly.
+    /// The string to write is obtained with [`to_string()`][ToString::to_string()].
+    pub fn from_decimal_compact(value: i64) -> Self {
+        if value != 0 {
+            for suffix in [Suffix::Gibi, Suffix::Mebi, Suffix::Kibi] {
+                let factor = 1_i64 << suffix.bitwise_offset();
+                if value % factor == 0 {
+                    return Integer {
+                        value: value / factor,
+                        suffix: Some(suffix),
+                    };
+                }
+            }
+        }
+        Integer { value, suffix: None }
+    }
+}
+
 impl Integer {
     /// Canonicalize values as simple decimal numbers.
     /// An optional suffix of k, m, or g (case-insensitive), will cause the
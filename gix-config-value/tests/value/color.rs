@@ -198,3 +198,58 @@ mod from_git {
         Ok(Color::try_from(name.into())?.to_string())
     }
 }
+
+mod to_ansi_escape_sequence {
+    use std::convert::TryFrom;
+
+    use bstr::BStr;
+    use gix_config_value::Color;
+
+    #[test]
+    fn empty_and_normal_produce_nothing() {
+        assert_eq!(ansi(""), "", "the empty value means 'no color'");
+        assert_eq!(ansi("normal"), "");
+        assert_eq!(ansi("normal normal"), "");
+    }
+
+    #[test]
+    fn reset() {
+        assert_eq!(ansi("reset"), "\x1b[m");
+        assert_eq!(ansi("reset bold"), "\x1b[;1m", "git leaves the reset position empty");
+    }
+
+    #[test]
+    fn attributes_come_first_and_are_sorted() {
+        assert_eq!(ansi("bold red"), "\x1b[1;31m");
+        assert_eq!(ansi("red ul bold"), "\x1b[1;4;31m");
+        assert_eq!(ansi("nobold"), "\x1b[22m");
+        assert_eq!(ansi("nobold nodim"), "\x1b[22m", "both negations share the same code");
+        assert_eq!(ansi("strike noreverse"), "\x1b[9;27m");
+    }
+
+    #[test]
+    fn foreground_and_background() {
+        assert_eq!(ansi("red blue"), "\x1b[31;44m");
+        assert_eq!(ansi("normal blue"), "\x1b[44m");
+        assert_eq!(ansi("default default"), "\x1b[39;49m");
+        assert_eq!(ansi("brightred brightwhite"), "\x1b[91;107m");
+    }
+
+    #[test]
+    fn numbered_colors() {
+        assert_eq!(ansi("1 2"), "\x1b[31;42m", "the first 8 are standard colors");
+        assert_eq!(ansi("9 10"), "\x1b[91;102m", "the next 8 are bright colors");
+        assert_eq!(ansi("254 255"), "\x1b[38;5;254;48;5;255m");
+    }
+
+    #[test]
+    fn rgb() {
+        assert_eq!(ansi("#ff0000 #000102"), "\x1b[38;2;255;0;0;48;2;0;1;2m");
+    }
+
+    fn ansi(input: &str) -> String {
+        Color::try_from(<&BStr>::from(input))
+            .expect("valid color")
+            .to_ansi_escape_sequence()
+    }
+}
@@ -73,3 +73,30 @@ fn as_decimal() {
     assert_eq!(decimal(&format!("{}g", i64::MAX)), None, "overflow results in None");
     assert_eq!(decimal(&format!("{}g", i64::MIN)), None, "underflow results in None");
 }
+
+#[test]
+fn from_decimal_compact() {
+    fn compact(value: i64) -> String {
+        Integer::from_decimal_compact(value).to_string()
+    }
+
+    assert_eq!(compact(0), "0");
+    assert_eq!(compact(1), "1");
+    assert_eq!(compact(1024), "1k");
+    assert_eq!(compact(1536), "1536", "no unit fits exactly");
+    assert_eq!(compact(3 * 1024 * 1024), "3m");
+    assert_eq!(compact(1024 * 1024 + 1024), "1025k");
+    assert_eq!(compact(2 * 1_073_741_824), "2g");
+    assert_eq!(compact(2048 * 1_073_741_824), "2048g", "there is no larger unit");
+    assert_eq!(compact(-1024), "-1k", "negative values work as well");
+    assert_eq!(compact(i64::MIN), "-8589934592g");
+    assert_eq!(compact(i64::MAX), i64::MAX.to_string());
+
+    for value in [0, 1, 1024, 1536, 3 * 1024 * 1024, -1024, i64::MIN, i64::MAX] {
+        assert_eq!(
+            Integer::try_from(b(&compact(value))).unwrap().to_decimal(),
+            Some(value),
+            "the rendered value round-trips"
+        );
+    }
+}
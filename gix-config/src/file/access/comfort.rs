@@ -188,6 +188,46 @@ impl<'event> File<'event> {
         self.integer_filter(key.section_name, key.subsection_name, key.value_name, filter)
     }
 
+    /// Like [`value()`][File::value()], but returning `None` if the color wasn't found.
+    ///
+    /// Note that an empty value is a valid color which means "no color".
+    pub fn color(
+        &self,
+        section_name: impl AsRef<str>,
+        subsection_name: Option<&BStr>,
+        key: impl AsRef<str>,
+    ) -> Option<Result<crate::Color, value::Error>> {
+        self.color_filter(section_name, subsection_name, key, &mut |_| true)
+    }
+
+    /// Like [`color()`][File::color()], but suitable for statically known `key`s like `color.diff.meta`.
+    pub fn color_by_key<'a>(&self, key: impl Into<&'a BStr>) -> Option<Result<crate::Color, value::Error>> {
+        self.color_filter_by_key(key, &mut |_| true)
+    }
+
+    /// Like [`color()`][File::color()], but the section containing the returned value must pass `filter` as well.
+    pub fn color_filter(
+        &self,
+        section_name: impl AsRef<str>,
+        subsection_name: Option<&BStr>,
+        key: impl AsRef<str>,
+        filter: &mut MetadataFilter,
+    ) -> Option<Result<crate::Color, value::Error>> {
+        self.raw_value_filter(section_name.as_ref(), subsection_name, key.as_ref(), filter)
+            .ok()
+            .map(crate::Color::try_from)
+    }
+
+    /// Like [`color_filter()`][File::color_filter()], but suitable for statically known `key`s like `color.diff.meta`.
+    pub fn color_filter_by_key<'a>(
+        &self,
+        key: impl Into<&'a BStr>,
+        filter: &mut MetadataFilter,
+    ) -> Option<Result<crate::Color, value::Error>> {
+        let key = crate::parse::key(key.into())?;
+        self.color_filter(key.section_name, key.subsection_name, key.value_name, filter)
+    }
+
     /// Similar to [`values(…)`][File::values()] but returning strings if at least one of them was found.
     pub fn strings(
         &self,
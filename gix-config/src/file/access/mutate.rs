@@ -4,7 +4,7 @@ use bstr::BStr;
 use gix_features::threading::OwnShared;
 
 use crate::{
-    file::{self, rename_section, write::ends_with_newline, MetadataFilter, SectionBodyIdsLut, SectionId, SectionMut},
+    file::{self, rename_section, write::ends_with_newline, InsertionPolicy, MetadataFilter, SectionId, SectionMut},
     lookup,
     parse::{section, Event, FrontMatterEvents},
     File,
@@ -72,7 +72,24 @@ impl<'event> File<'event> {
         subsection_name: Option<&BStr>,
         filter: &mut MetadataFilter,
     ) -> Result<SectionMut<'a, 'event>, section::header::Error> {
-        self.section_mut_or_create_new_filter_inner(name.as_ref(), subsection_name, filter)
+        self.section_mut_or_create_new_filter_inner(
+            name.as_ref(),
+            subsection_name,
+            filter,
+            InsertionPolicy::AppendToEnd,
+        )
+    }
+
+    /// Like [`section_mut_or_create_new_filter()`][File::section_mut_or_create_new_filter()], but places a newly created
+    /// section according to `policy`.
+    pub fn section_mut_or_create_new_filter_with_policy<'a>(
+        &'a mut self,
+        name: impl AsRef<str>,
+        subsection_name: Option<&BStr>,
+        filter: &mut MetadataFilter,
+        policy: InsertionPolicy,
+    ) -> Result<SectionMut<'a, 'event>, section::header::Error> {
+        self.section_mut_or_create_new_filter_inner(name.as_ref(), subsection_name, filter, policy)
     }
 
     fn section_mut_or_create_new_filter_inner<'a>(
@@ -80,6 +97,7 @@ impl<'event> File<'event> {
         name: &str,
         subsection_name: Option<&BStr>,
         filter: &mut MetadataFilter,
+        policy: InsertionPolicy,
     ) -> Result<SectionMut<'a, 'event>, section::header::Error> {
         match self
             .section_ids_by_name_and_subname(name.as_ref(), subsection_name)
@@ -98,7 +116,11 @@ impl<'event> File<'event> {
                     .expect("BUG: Section did not have id from lookup")
                     .to_mut(nl))
             }
-            None => self.new_section(name.to_owned(), subsection_name.map(|n| Cow::Owned(n.to_owned()))),
+            None => self.new_section_inner(
+                name.to_owned().into(),
+                subsection_name.map(|n| Cow::Owned(n.to_owned())),
+                policy,
+            ),
         }
     }
 
@@ -184,15 +206,51 @@ impl<'event> File<'event> {
         name: impl Into<Cow<'event, str>>,
         subsection: impl Into<Option<Cow<'event, BStr>>>,
     ) -> Result<SectionMut<'_, 'event>, section::header::Error> {
-        self.new_section_inner(name.into(), subsection.into())
+        self.new_section_inner(name.into(), subsection.into(), InsertionPolicy::AppendToEnd)
+    }
+
+    /// Like [`new_section()`][File::new_section()], but places the new section according to `policy`.
+    ///
+    /// # Examples
+    ///
+    /// Grouping a new section with existing sections of the same name:
+    ///
+    /// ```
+    /// # use std::borrow::Cow;
+    /// # use gix_config::file::InsertionPolicy;
+    /// let mut git_config = gix_config::File::try_from("[remote \"a\"]\n[core]\n")?;
+    /// git_config.new_section_with_policy("remote", Some(Cow::Borrowed("b".into())), InsertionPolicy::AfterLastSameNamed)?;
+    /// assert_eq!(git_config.to_string(), "[remote \"a\"]\n[remote \"b\"]\n[core]\n");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new_section_with_policy(
+        &mut self,
+        name: impl Into<Cow<'event, str>>,
+        subsection: impl Into<Option<Cow<'event, BStr>>>,
+        policy: InsertionPolicy,
+    ) -> Result<SectionMut<'_, 'event>, section::header::Error> {
+        self.new_section_inner(name.into(), subsection.into(), policy)
     }
 
     fn new_section_inner(
         &mut self,
         name: Cow<'event, str>,
         subsection: Option<Cow<'event, BStr>>,
+        policy: InsertionPolicy,
     ) -> Result<SectionMut<'_, 'event>, section::header::Error> {
-        let id = self.push_section_internal(file::Section::new(name, subsection, OwnShared::clone(&self.meta))?);
+        let anchor = match policy {
+            InsertionPolicy::AppendToEnd => None,
+            InsertionPolicy::AfterLastSameNamed => self.section_ids_by_name(&name).ok().and_then(Iterator::last),
+            InsertionPolicy::BeforeFirstSameNamed => {
+                self.section_ids_by_name(&name).ok().and_then(|mut ids| ids.next())
+            }
+        };
+        let section = file::Section::new(name, subsection, OwnShared::clone(&self.meta))?;
+        let id = match (anchor, policy) {
+            (Some(last), InsertionPolicy::AfterLastSameNamed) => self.insert_section_after(section, last),
+            (Some(first), InsertionPolicy::BeforeFirstSameNamed) => self.insert_section_before(section, first),
+            _ => self.push_section_internal(section),
+        };
         let nl = self.detect_newline_style_smallvec();
         let mut section = self.sections.get_mut(&id).expect("each id yields a section").to_mut(nl);
         section.push_newline();
@@ -255,29 +313,7 @@ impl<'event> File<'event> {
         self.section_order
             .remove(self.section_order.iter().position(|v| *v == id)?);
         let section = self.sections.remove(&id)?;
-        let lut = self
-            .section_lookup_tree
-            .get_mut(&section.header.name)
-            .expect("lookup cache still has name to be deleted");
-        // NOTE: this leaves empty lists in the data structure which our code now has to deal with.
-        for entry in lut {
-            match section.header.subsection_name.as_deref() {
-                Some(subsection_name) => {
-                    if let SectionBodyIdsLut::NonTerminal(map) = entry {
-                        if let Some(ids) = map.get_mut(subsection_name) {
-                            ids.remove(ids.iter().position(|v| *v == id).expect("present"));
-                            break;
-                        }
-                    }
-                }
-                None => {
-                    if let SectionBodyIdsLut::Terminal(ids) = entry {
-                        ids.remove(ids.iter().position(|v| *v == id).expect("present"));
-                        break;
-                    }
-                }
-            }
-        }
+        self.remove_from_lookup(id, &section.header);
         Some(section)
     }
 
@@ -325,6 +361,11 @@ impl<'event> File<'event> {
 
     /// Renames the section with `name` and `subsection_name`, modifying the last matching section
     /// to use `new_name` and `new_subsection_name`.
+    ///
+    /// Only the header is changed, so the section keeps its position, its values including their formatting, and its metadata.
+    /// If a section with the new name already exists, the renamed section's values are merged with it when looking them up,
+    /// just like it would be the case for multiple sections of the same name in a file.
+    /// Use [`rename_section_or_error()`][Self::rename_section_or_error()] if this isn't desired.
     pub fn rename_section<'a>(
         &mut self,
         name: impl AsRef<str>,
@@ -332,13 +373,21 @@ impl<'event> File<'event> {
         new_name: impl Into<Cow<'event, str>>,
         new_subsection_name: impl Into<Option<Cow<'event, BStr>>>,
     ) -> Result<(), rename_section::Error> {
-        let id = self
-            .section_ids_by_name_and_subname(name.as_ref(), subsection_name.into())?
-            .next_back()
-            .expect("list of sections were empty, which violates invariant");
-        let section = self.sections.get_mut(&id).expect("known section-id");
-        section.header = section::Header::new(new_name, new_subsection_name)?;
-        Ok(())
+        let id = self.last_section_id(name.as_ref(), subsection_name.into())?;
+        self.rename_section_by_id(id, section::Header::new(new_name, new_subsection_name)?, false)
+    }
+
+    /// Like [`rename_section()`][Self::rename_section()], but fail with [`rename_section::Error::Exists`] if a section
+    /// with `new_name` and `new_subsection_name` already exists, leaving all sections unchanged.
+    pub fn rename_section_or_error<'a>(
+        &mut self,
+        name: impl AsRef<str>,
+        subsection_name: impl Into<Option<&'a BStr>>,
+        new_name: impl Into<Cow<'event, str>>,
+        new_subsection_name: impl Into<Option<Cow<'event, BStr>>>,
+    ) -> Result<(), rename_section::Error> {
+        let id = self.last_section_id(name.as_ref(), subsection_name.into())?;
+        self.rename_section_by_id(id, section::Header::new(new_name, new_subsection_name)?, true)
     }
 
     /// Renames the section with `name` and `subsection_name`, modifying the last matching section
@@ -354,13 +403,60 @@ impl<'event> File<'event> {
         new_subsection_name: impl Into<Option<Cow<'event, BStr>>>,
         filter: &mut MetadataFilter,
     ) -> Result<(), rename_section::Error> {
-        let id = self
-            .section_ids_by_name_and_subname(name.as_ref(), subsection_name.into())?
+        let id = self.last_section_id_filter(name.as_ref(), subsection_name.into(), filter)?;
+        self.rename_section_by_id(id, section::Header::new(new_name, new_subsection_name)?, false)
+    }
+
+    /// Like [`rename_section_filter()`][Self::rename_section_filter()], but fail with [`rename_section::Error::Exists`]
+    /// if a section with `new_name` and `new_subsection_name` already exists, leaving all sections unchanged.
+    pub fn rename_section_filter_or_error<'a>(
+        &mut self,
+        name: impl AsRef<str>,
+        subsection_name: impl Into<Option<&'a BStr>>,
+        new_name: impl Into<Cow<'event, str>>,
+        new_subsection_name: impl Into<Option<Cow<'event, BStr>>>,
+        filter: &mut MetadataFilter,
+    ) -> Result<(), rename_section::Error> {
+        let id = self.last_section_id_filter(name.as_ref(), subsection_name.into(), filter)?;
+        self.rename_section_by_id(id, section::Header::new(new_name, new_subsection_name)?, true)
+    }
+
+    fn last_section_id(&self, name: &str, subsection_name: Option<&BStr>) -> Result<SectionId, rename_section::Error> {
+        Ok(self
+            .section_ids_by_name_and_subname(name, subsection_name)?
+            .next_back()
+            .expect("list of sections were empty, which violates invariant"))
+    }
+
+    fn last_section_id_filter(
+        &self,
+        name: &str,
+        subsection_name: Option<&BStr>,
+        filter: &mut MetadataFilter,
+    ) -> Result<SectionId, rename_section::Error> {
+        self.section_ids_by_name_and_subname(name, subsection_name)?
             .rev()
             .find(|id| filter(self.sections.get(id).expect("each id has a section").meta()))
-            .ok_or(rename_section::Error::Lookup(lookup::existing::Error::KeyMissing))?;
-        let section = self.sections.get_mut(&id).expect("known section-id");
-        section.header = section::Header::new(new_name, new_subsection_name)?;
+            .ok_or(rename_section::Error::Lookup(lookup::existing::Error::KeyMissing))
+    }
+
+    fn rename_section_by_id(
+        &mut self,
+        id: SectionId,
+        header: section::Header<'event>,
+        error_if_exists: bool,
+    ) -> Result<(), rename_section::Error> {
+        if error_if_exists {
+            let exists = self
+                .section_ids_by_name_and_subname(header.name.as_ref(), header.subsection_name.as_deref())
+                .map_or(false, |mut ids| ids.any(|other| other != id));
+            if exists {
+                return Err(rename_section::Error::Exists {
+                    header: header.to_bstring(),
+                });
+            }
+        }
+        self.set_section_header(id, header);
         Ok(())
     }
 
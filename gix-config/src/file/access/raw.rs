@@ -43,6 +43,45 @@ impl<'event> File<'event> {
         self.raw_value_filter_inner(section_name.as_ref(), subsection_name, key.as_ref(), filter)
     }
 
+    /// Returns the location of the key and value that [`raw_value()`][Self::raw_value()] would return in the input it was
+    /// parsed from, or `None` if the value was added or changed after parsing.
+    ///
+    /// This is useful to point users to the definition of a value, but note that the location of keys is only
+    /// recorded if the file was parsed with [`key_spans`][crate::file::init::Options::key_spans] enabled.
+    /// See [`Section::key_span()`][crate::file::Section::key_span()] for details.
+    pub fn raw_value_span(
+        &self,
+        section_name: impl AsRef<str>,
+        subsection_name: Option<&BStr>,
+        key: impl AsRef<str>,
+    ) -> Result<Option<crate::parse::Span>, lookup::existing::Error> {
+        self.raw_value_span_filter(section_name, subsection_name, key, &mut |_| true)
+    }
+
+    /// Like [`raw_value_span()`][Self::raw_value_span()], but only considers sections that pass `filter`,
+    /// just like [`raw_value_filter()`][Self::raw_value_filter()].
+    pub fn raw_value_span_filter(
+        &self,
+        section_name: impl AsRef<str>,
+        subsection_name: Option<&BStr>,
+        key: impl AsRef<str>,
+        filter: &mut MetadataFilter,
+    ) -> Result<Option<crate::parse::Span>, lookup::existing::Error> {
+        let key = key.as_ref();
+        let section_ids = self.section_ids_by_name_and_subname(section_name.as_ref(), subsection_name)?;
+        for section_id in section_ids.rev() {
+            let section = self.sections.get(&section_id).expect("known section id");
+            if !filter(section.meta()) {
+                continue;
+            }
+            if section.value(key).is_some() {
+                return Ok(section.key_span(key));
+            }
+        }
+
+        Err(lookup::existing::Error::KeyMissing)
+    }
+
     fn raw_value_filter_inner(
         &self,
         section_name: &str,
@@ -417,6 +456,9 @@ impl<'event> File<'event> {
     /// Sets a value in a given `section_name`, optional `subsection_name`, and `key`.
     /// Creates the section if necessary and the key as well, or overwrites the last existing value otherwise.
     ///
+    /// As all parts are passed separately, they are used as is, so `subsection_name` may contain `.` characters
+    /// without being mistaken for a separator as it could be the case with a dotted key like `remote.origin.url`.
+    ///
     /// # Examples
     ///
     /// Given the config,
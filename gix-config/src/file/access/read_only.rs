@@ -268,6 +268,21 @@ impl<'event> File<'event> {
         self.sections.values().map(|section| section.num_values()).sum()
     }
 
+    /// Returns the number of sections in the config, including empty ones.
+    #[must_use]
+    pub fn num_sections(&self) -> usize {
+        self.sections.len()
+    }
+
+    /// Returns `true` if there are neither sections nor front-matter like comments or whitespace,
+    /// so serializing this instance wouldn't produce any output.
+    ///
+    /// Use [`is_void()`][File::is_void()] to also consider files with only empty sections as empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty() && self.frontmatter_events.is_empty()
+    }
+
     /// Returns if there are no entries in the config. This will return true
     /// if there are only empty sections, with whitespace and comments not being considered
     /// void.
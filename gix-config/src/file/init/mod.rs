@@ -36,10 +36,12 @@ impl<'a> File<'a> {
         options: Options<'_>,
     ) -> Result<Self, Error> {
         let meta = meta.into();
-        Ok(Self::from_parse_events_no_includes(
-            parse::Events::from_bytes(input, options.to_event_filter())?,
-            meta,
-        ))
+        let events = if options.key_spans {
+            parse::Events::from_bytes_with_key_spans(input, options.to_event_filter())?
+        } else {
+            parse::Events::from_bytes(input, options.to_event_filter())?
+        };
+        Ok(Self::from_parse_events_no_includes(events, meta))
     }
 
     /// Instantiate a new `File` from given `events`, associating each section and their values with
@@ -59,6 +61,8 @@ impl<'a> File<'a> {
                 body: section::Body(section.events),
                 meta: OwnShared::clone(&meta),
                 id: Default::default(),
+                span: section.span,
+                key_spans: section.key_spans,
             });
         }
 
@@ -75,10 +79,12 @@ impl File<'static> {
         meta: impl Into<OwnShared<Metadata>>,
         options: Options<'_>,
     ) -> Result<Self, Error> {
-        let mut config = Self::from_parse_events_no_includes(
-            parse::Events::from_bytes_owned(input_and_buf, options.to_event_filter()).map_err(Error::from)?,
-            meta,
-        );
+        let events = if options.key_spans {
+            parse::Events::from_bytes_owned_with_key_spans(input_and_buf, options.to_event_filter())
+        } else {
+            parse::Events::from_bytes_owned(input_and_buf, options.to_event_filter())
+        };
+        let mut config = Self::from_parse_events_no_includes(events.map_err(Error::from)?, meta);
 
         includes::resolve(&mut config, input_and_buf, options).map_err(Error::from)?;
         Ok(config)
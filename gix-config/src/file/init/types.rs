@@ -26,6 +26,11 @@ pub struct Options<'a> {
     ///
     /// That way it's possible to pass multiple files and read as many as possible, to have 'something' instead of nothing.
     pub ignore_io_errors: bool,
+    /// If true, the location of each key along with its value will be recorded in addition to the location of sections,
+    /// for use with [`File::raw_value_span()`][crate::File::raw_value_span()].
+    ///
+    /// It's off by default as it's only needed by tools that point users to the definition of values.
+    pub key_spans: bool,
 }
 
 impl Options<'_> {
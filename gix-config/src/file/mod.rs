@@ -35,6 +35,8 @@ pub mod rename_section {
         Lookup(#[from] crate::lookup::existing::Error),
         #[error(transparent)]
         Section(#[from] crate::parse::section::header::Error),
+        #[error("Section {header} already exists")]
+        Exists { header: bstr::BString },
     }
 }
 
@@ -67,12 +69,32 @@ pub struct Metadata {
 }
 
 /// A section in a git-config file, like `[core]` or `[remote "origin"]`, along with all of its keys.
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+///
+/// Note that the location of the section and its keys isn't considered when comparing or hashing sections.
+#[derive(Clone, Debug)]
 pub struct Section<'a> {
     header: crate::parse::section::Header<'a>,
     body: section::Body<'a>,
     meta: OwnShared<Metadata>,
     id: SectionId,
+    span: Option<crate::parse::Span>,
+    key_spans: Vec<(usize, crate::parse::Span)>,
+}
+
+/// Determines where a newly created section is placed among the existing sections of a [`File`][crate::File].
+///
+/// Sections are considered *same-named* if their name matches, independently of their subsection name, so `[remote "a"]`
+/// and `[remote "b"]` are grouped together.
+/// If there is no same-named section, a new section is always appended to the end.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InsertionPolicy {
+    /// Append the new section after all other sections.
+    #[default]
+    AppendToEnd,
+    /// Insert the new section right after the last section with the same name.
+    AfterLastSameNamed,
+    /// Insert the new section right before the first section with the same name.
+    BeforeFirstSameNamed,
 }
 
 /// A function to filter metadata, returning `true` if the corresponding but omitted value can be used.
@@ -108,7 +108,7 @@ impl<'borrow, 'lookup, 'event> MultiValueMut<'borrow, 'lookup, 'event> {
         MultiValueMut::set_value_inner(
             &self.key,
             &mut self.offsets,
-            &mut self.section.get_mut(&section_id).expect("known section id").body,
+            self.section.get_mut(&section_id).expect("known section id"),
             section_id,
             offset_index,
             value.into(),
@@ -138,7 +138,7 @@ impl<'borrow, 'lookup, 'event> MultiValueMut<'borrow, 'lookup, 'event> {
             Self::set_value_inner(
                 &self.key,
                 &mut self.offsets,
-                &mut self.section.get_mut(section_id).expect("known section id").body,
+                self.section.get_mut(section_id).expect("known section id"),
                 *section_id,
                 *offset_index,
                 value.into(),
@@ -158,7 +158,7 @@ impl<'borrow, 'lookup, 'event> MultiValueMut<'borrow, 'lookup, 'event> {
             Self::set_value_inner(
                 &self.key,
                 &mut self.offsets,
-                &mut self.section.get_mut(section_id).expect("known section id").body,
+                self.section.get_mut(section_id).expect("known section id"),
                 *section_id,
                 *offset_index,
                 input,
@@ -169,21 +169,23 @@ impl<'borrow, 'lookup, 'event> MultiValueMut<'borrow, 'lookup, 'event> {
     fn set_value_inner<'a: 'event>(
         key: &section::Key<'lookup>,
         offsets: &mut HashMap<SectionId, Vec<usize>>,
-        section: &mut file::section::Body<'event>,
+        section: &mut file::Section<'event>,
         section_id: SectionId,
         offset_index: usize,
         value: &BStr,
     ) {
         let (offset, size) = MultiValueMut::index_and_size(offsets, section_id, offset_index);
-        let whitespace = Whitespace::from_body(section);
-        let section = section.as_mut();
-        section.drain(offset..offset + size);
+        let whitespace = Whitespace::from_body(&section.body);
+        let body = section.body.as_mut();
+        let len_before = body.len();
+        body.drain(offset..offset + size);
 
         let key_sep_events = whitespace.key_value_separators();
         MultiValueMut::set_offset(offsets, section_id, offset_index, 2 + key_sep_events.len());
-        section.insert(offset, Event::Value(escape_value(value).into()));
-        section.insert_many(offset, key_sep_events.into_iter().rev());
-        section.insert(offset, Event::SectionKey(key.to_owned()));
+        body.insert(offset, Event::Value(escape_value(value).into()));
+        body.insert_many(offset, key_sep_events.into_iter().rev());
+        body.insert(offset, Event::SectionKey(key.to_owned()));
+        section.update_key_spans(offset..offset + size, len_before);
     }
 
     /// Removes the value at the given index. Does nothing when called multiple
@@ -201,12 +203,10 @@ impl<'borrow, 'lookup, 'event> MultiValueMut<'borrow, 'lookup, 'event> {
         if size == 0 {
             return;
         }
-        self.section
-            .get_mut(section_id)
-            .expect("known section id")
-            .body
-            .as_mut()
-            .drain(offset..offset + size);
+        let section = self.section.get_mut(section_id).expect("known section id");
+        let len_before = section.body.0.len();
+        section.body.as_mut().drain(offset..offset + size);
+        section.update_key_spans(offset..offset + size, len_before);
 
         Self::set_offset(&mut self.offsets, *section_id, *offset_index, 0);
         self.indices_and_sizes.remove(index);
@@ -224,12 +224,10 @@ impl<'borrow, 'lookup, 'event> MultiValueMut<'borrow, 'lookup, 'event> {
             if size == 0 {
                 continue;
             }
-            self.section
-                .get_mut(section_id)
-                .expect("known section id")
-                .body
-                .as_mut()
-                .drain(offset..offset + size);
+            let section = self.section.get_mut(section_id).expect("known section id");
+            let len_before = section.body.0.len();
+            section.body.as_mut().drain(offset..offset + size);
+            section.update_key_spans(offset..offset + size, len_before);
             Self::set_offset(&mut self.offsets, *section_id, *offset_index, 0);
         }
         self.indices_and_sizes.clear();
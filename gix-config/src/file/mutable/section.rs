@@ -92,6 +92,9 @@ impl<'a, 'event> SectionMut<'a, 'event> {
         while let Some(e) = body.pop() {
             match e {
                 Event::SectionKey(k) => {
+                    self.section
+                        .key_spans
+                        .retain(|(event_index, _)| *event_index < body.len());
                     // pop leading whitespace
                     if let Some(Event::Whitespace(_)) = body.last() {
                         body.pop();
@@ -130,6 +133,7 @@ impl<'a, 'event> SectionMut<'a, 'event> {
                 None
             }
             Some((key_range, value_range)) => {
+                let len_before = self.section.body.0.len();
                 let value_range = value_range.unwrap_or(key_range.end - 1..key_range.end);
                 let range_start = value_range.start;
                 let ret = self.remove_internal(value_range, false);
@@ -137,6 +141,7 @@ impl<'a, 'event> SectionMut<'a, 'event> {
                     .body
                     .0
                     .insert(range_start, Event::Value(escape_value(value).into()));
+                self.section.update_key_spans(key_range, len_before);
                 Some(ret)
             }
         }
@@ -146,7 +151,10 @@ impl<'a, 'event> SectionMut<'a, 'event> {
     pub fn remove(&mut self, key: &str) -> Option<Cow<'event, BStr>> {
         let key = Key::from_str_unchecked(key);
         let (key_range, _value_range) = self.key_and_value_range_by(&key)?;
-        Some(self.remove_internal(key_range, true))
+        let len_before = self.section.body.0.len();
+        let value = self.remove_internal(key_range.clone(), true);
+        self.section.update_key_spans(key_range, len_before);
+        Some(value)
     }
 
     /// Adds a new line event. Note that you don't need to call this unless
@@ -250,13 +258,16 @@ impl<'a, 'event> SectionMut<'a, 'event> {
     }
 
     pub(crate) fn delete(&mut self, start: Index, end: Index) {
+        let len_before = self.section.body.0.len();
         self.section.body.0.drain(start.0..end.0);
+        self.section.update_key_spans(start.0..end.0, len_before);
     }
 
     pub(crate) fn set_internal(&mut self, index: Index, key: Key<'event>, value: &BStr) -> Size {
         let mut size = 0;
 
         let body = &mut self.section.body.0;
+        let len_before = body.len();
         body.insert(index.0, Event::Value(escape_value(value).into()));
         size += 1;
 
@@ -267,6 +278,7 @@ impl<'a, 'event> SectionMut<'a, 'event> {
         body.insert(index.0, Event::SectionKey(key));
         size += 1;
 
+        self.section.update_key_spans(index.0..index.0, len_before);
         Size(size)
     }
 
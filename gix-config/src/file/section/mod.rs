@@ -1,4 +1,7 @@
-use std::{borrow::Cow, ops::Deref};
+use std::{
+    borrow::Cow,
+    ops::{Deref, Range},
+};
 
 use bstr::{BStr, BString, ByteSlice};
 use smallvec::SmallVec;
@@ -40,6 +43,8 @@ impl<'a> Section<'a> {
             body: Default::default(),
             meta: meta.into(),
             id: SectionId::default(),
+            span: None,
+            key_spans: Vec::new(),
         })
     }
 }
@@ -62,6 +67,30 @@ impl<'a> Section<'a> {
         &self.body
     }
 
+    /// Return the location of this section in the input it was parsed from, or `None` if it was created
+    /// programmatically.
+    ///
+    /// Note that the span isn't updated when the section is modified, and that it refers to the input of the file
+    /// the section was originally parsed from, which might be an included file as indicated by its [metadata][Self::meta()].
+    pub fn span(&self) -> Option<parse::Span> {
+        self.span
+    }
+
+    /// Return the location of the last `key` along with its value in the input this section was parsed from,
+    /// or `None` if there is no such key or if it was added or changed after parsing.
+    ///
+    /// Just like [`span()`][Self::span()], it refers to the input the section was parsed from, which is useful to
+    /// point users to the definition of a value. Note that keys only have a location if they were parsed
+    /// with [`key_spans`][crate::file::init::Options::key_spans] enabled.
+    pub fn key_span(&self, key: impl AsRef<str>) -> Option<parse::Span> {
+        let key = section::Key::from_str_unchecked(key.as_ref());
+        let (key_range, _value_range) = self.body.key_and_value_range_by(&key)?;
+        self.key_spans
+            .binary_search_by_key(&key_range.start, |(event_index, _)| *event_index)
+            .ok()
+            .map(|idx| self.key_spans[idx].1)
+    }
+
     /// Serialize this type into a `BString` for convenience.
     ///
     /// Note that `to_string()` can also be used, but might not be lossless.
@@ -143,3 +172,53 @@ impl<'a> Section<'a> {
         SectionMut::new(self, newline)
     }
 }
+
+impl<'a> Section<'a> {
+    /// Forget the location of keys whose events were in the `replaced` range of events, and move the event index of
+    /// keys after it to account for the change of length of our body, which had `len_before` events before it was changed.
+    pub(crate) fn update_key_spans(&mut self, replaced: Range<usize>, len_before: usize) {
+        let len_after = self.body.0.len();
+        self.key_spans.retain_mut(|(event_index, _)| {
+            if replaced.contains(event_index) {
+                return false;
+            }
+            if *event_index >= replaced.end {
+                *event_index = *event_index + len_after - len_before;
+            }
+            true
+        });
+    }
+}
+
+impl PartialEq for Section<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.header == other.header && self.body == other.body && self.meta == other.meta && self.id == other.id
+    }
+}
+
+impl Eq for Section<'_> {}
+
+impl PartialOrd for Section<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Section<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.header
+            .cmp(&other.header)
+            .then_with(|| self.body.cmp(&other.body))
+            .then_with(|| self.meta.cmp(&other.meta))
+            .then_with(|| self.id.cmp(&other.id))
+    }
+}
+
+impl std::hash::Hash for Section<'_> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.header.hash(state);
+        self.body.hash(state);
+        self.meta.hash(state);
+        self.id.hash(state);
+    }
+}
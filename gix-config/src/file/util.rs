@@ -1,4 +1,4 @@
-use std::{cmp::Ordering, collections::HashMap};
+use std::collections::HashMap;
 
 use bstr::BStr;
 
@@ -54,7 +54,45 @@ impl<'event> File<'event> {
     }
 
     /// Inserts `section` after the section that comes `before` it, and maintains correct ordering in all of our lookup structures.
-    pub(crate) fn insert_section_after(&mut self, mut section: file::Section<'event>, before: SectionId) -> SectionId {
+    pub(crate) fn insert_section_after(&mut self, section: file::Section<'event>, before: SectionId) -> SectionId {
+        let before_order = self.section_order_of(before).expect("before-section exists");
+        self.insert_section_at(section, before_order + 1)
+    }
+
+    /// Inserts `section` before the section that comes `after` it, and maintains correct ordering in all of our lookup structures.
+    pub(crate) fn insert_section_before(&mut self, section: file::Section<'event>, after: SectionId) -> SectionId {
+        let after_order = self.section_order_of(after).expect("after-section exists");
+        self.insert_section_at(section, after_order)
+    }
+
+    /// Return the position of the section with `id` in the order of all sections.
+    fn section_order_of(&self, id: SectionId) -> Option<usize> {
+        self.section_order.iter().position(|candidate| *candidate == id)
+    }
+
+    /// Inserts `section` so it ends up at `insert_order` in the order of all sections.
+    fn insert_section_at(&mut self, mut section: file::Section<'event>, insert_order: usize) -> SectionId {
+        let new_section_id = SectionId(self.section_id_counter);
+        section.id = new_section_id;
+        self.sections.insert(new_section_id, section);
+        self.insert_into_lookup(new_section_id, insert_order);
+        self.section_order.insert(insert_order, new_section_id);
+        self.section_id_counter += 1;
+        new_section_id
+    }
+
+    /// Change the header of the section with `id` to `header`, keeping its id and its position among all sections.
+    pub(crate) fn set_section_header(&mut self, id: SectionId, header: section::Header<'event>) {
+        let section = self.sections.get_mut(&id).expect("known section-id");
+        let previous_header = std::mem::replace(&mut section.header, header);
+        self.remove_from_lookup(id, &previous_header);
+        let order = self.section_order_of(id).expect("known section-id");
+        self.insert_into_lookup(id, order);
+    }
+
+    /// Add the section with `id` to the lookup table under its current header, so that it's sorted as if it was at
+    /// `insert_order` among all sections.
+    fn insert_into_lookup(&mut self, id: SectionId, insert_order: usize) {
         let lookup_section_order = {
             let section_order = &self.section_order;
             move |section_id| {
@@ -62,15 +100,11 @@ impl<'event> File<'event> {
                     .iter()
                     .enumerate()
                     .find_map(|(idx, id)| (*id == section_id).then_some(idx))
-                    .expect("before-section exists")
+                    .expect("section in lookup table exists")
             }
         };
 
-        let before_order = lookup_section_order(before);
-        let new_section_id = SectionId(self.section_id_counter);
-        section.id = new_section_id;
-        self.sections.insert(new_section_id, section);
-        let header = &self.sections[&new_section_id].header;
+        let header = &self.sections[&id].header;
         let lookup = self.section_lookup_tree.entry(header.name.clone()).or_default();
 
         let mut found_node = false;
@@ -82,35 +116,75 @@ impl<'event> File<'event> {
                         subsections.entry(subsection_name.clone()).or_default();
                     let insert_pos = find_insert_pos_by_order(
                         sections_with_name_and_subsection_name,
-                        before_order,
+                        insert_order,
                         lookup_section_order,
                     );
-                    sections_with_name_and_subsection_name.insert(insert_pos, new_section_id);
+                    sections_with_name_and_subsection_name.insert(insert_pos, id);
                     break;
                 }
             }
             if !found_node {
                 let mut map = HashMap::new();
-                map.insert(subsection_name, vec![new_section_id]);
+                map.insert(subsection_name, vec![id]);
                 lookup.push(SectionBodyIdsLut::NonTerminal(map));
             }
         } else {
             for node in lookup.iter_mut() {
                 if let SectionBodyIdsLut::Terminal(sections_with_name) = node {
                     found_node = true;
-                    let insert_pos = find_insert_pos_by_order(sections_with_name, before_order, lookup_section_order);
-                    sections_with_name.insert(insert_pos, new_section_id);
+                    let insert_pos = find_insert_pos_by_order(sections_with_name, insert_order, lookup_section_order);
+                    sections_with_name.insert(insert_pos, id);
                     break;
                 }
             }
             if !found_node {
-                lookup.push(SectionBodyIdsLut::Terminal(vec![new_section_id]));
+                lookup.push(SectionBodyIdsLut::Terminal(vec![id]));
             }
         }
+    }
 
-        self.section_order.insert(before_order + 1, new_section_id);
-        self.section_id_counter += 1;
-        new_section_id
+    /// Remove the section with `id` from the lookup table, where it's stored under `header`.
+    ///
+    /// Entries that are left without sections are removed as well, so lookups never yield empty lists of sections.
+    pub(crate) fn remove_from_lookup(&mut self, id: SectionId, header: &section::Header<'event>) {
+        let lut = self
+            .section_lookup_tree
+            .get_mut(&header.name)
+            .expect("lookup cache still has name to be deleted");
+        let mut empty_node = None;
+        for (node_idx, entry) in lut.iter_mut().enumerate() {
+            match header.subsection_name.as_deref() {
+                Some(subsection_name) => {
+                    if let SectionBodyIdsLut::NonTerminal(map) = entry {
+                        if let Some(ids) = map.get_mut(subsection_name) {
+                            ids.remove(ids.iter().position(|v| *v == id).expect("present"));
+                            if ids.is_empty() {
+                                map.remove(subsection_name);
+                            }
+                            if map.is_empty() {
+                                empty_node = Some(node_idx);
+                            }
+                            break;
+                        }
+                    }
+                }
+                None => {
+                    if let SectionBodyIdsLut::Terminal(ids) = entry {
+                        ids.remove(ids.iter().position(|v| *v == id).expect("present"));
+                        if ids.is_empty() {
+                            empty_node = Some(node_idx);
+                        }
+                        break;
+                    }
+                }
+            }
+        }
+        if let Some(node_idx) = empty_node {
+            lut.remove(node_idx);
+            if lut.is_empty() {
+                self.section_lookup_tree.remove(&header.name);
+            }
+        }
     }
 
     /// Returns the mapping between section and subsection name to section ids.
@@ -166,25 +240,15 @@ impl<'event> File<'event> {
     }
 }
 
+/// Return the position at which to insert a section into `sections_with_name` so that it remains sorted by section order
+/// after placing the new section at `insert_order`.
 fn find_insert_pos_by_order(
     sections_with_name: &[SectionId],
-    before_order: usize,
+    insert_order: usize,
     lookup_section_order: impl Fn(SectionId) -> usize,
 ) -> usize {
-    let mut insert_pos = sections_with_name.len(); // push back by default
-    for (idx, candidate_id) in sections_with_name.iter().enumerate() {
-        let candidate_order = lookup_section_order(*candidate_id);
-        match candidate_order.cmp(&before_order) {
-            Ordering::Less => {}
-            Ordering::Equal => {
-                insert_pos = idx + 1; // insert right after this one
-                break;
-            }
-            Ordering::Greater => {
-                insert_pos = idx; // insert before this one
-                break;
-            }
-        }
-    }
-    insert_pos
+    sections_with_name
+        .iter()
+        .position(|candidate_id| lookup_section_order(*candidate_id) >= insert_order)
+        .unwrap_or(sections_with_name.len())
 }
@@ -65,6 +65,12 @@ impl File<'_> {
 
     /// Stream ourselves to the given `out`, in order to reproduce this file mostly losslessly
     /// as it was parsed.
+    ///
+    /// All events are written as they were parsed, including whitespace, comments, newlines and
+    /// value continuations, so an unmodified file is reproduced byte-for-byte with only one exception:
+    /// if the file has at least one section and its last line isn't terminated, a newline is added.
+    /// Files without sections, like those containing only comments, are reproduced as is.
+    /// Edits, like [setting a value][File::set_raw_value()], only change the bytes of the value they affect.
     pub fn write_to(&self, out: &mut dyn std::io::Write) -> std::io::Result<()> {
         self.write_to_filter(out, &mut |_| true)
     }
@@ -0,0 +1,15 @@
+use crate::{file::Metadata, Source};
+
+/// Return a filter which only passes sections that originate from `source`.
+///
+/// Use it like `file.string_filter("core", None, "bare", &mut by_source(Source::Local))`.
+pub fn by_source(source: Source) -> impl FnMut(&Metadata) -> bool {
+    move |meta| meta.source == source
+}
+
+/// Return a filter which only passes sections whose include-level is at most `level`.
+///
+/// A `level` of `0` thus excludes all values that were brought in by `include.path` or `includeIf.<condition>.path`.
+pub fn by_max_level(level: u8) -> impl FnMut(&Metadata) -> bool {
+    move |meta| meta.level <= level
+}
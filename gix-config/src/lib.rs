@@ -39,6 +39,8 @@
 
 pub mod file;
 
+/// Ready-made [metadata filters][crate::file::MetadataFilter] for use with all `*_filter(…)` methods of [`File`].
+pub mod filter;
 ///
 pub mod lookup;
 pub mod parse;
@@ -4,8 +4,8 @@
 pub enum Error<E> {
     #[error(transparent)]
     ValueMissing(#[from] existing::Error),
-    #[error(transparent)]
-    FailedConversion(E),
+    #[error("The value could not be converted into the requested type")]
+    FailedConversion(#[source] E),
 }
 
 ///
@@ -229,7 +229,16 @@ impl Events<'static> {
         input: &'a [u8],
         filter: Option<fn(&Event<'a>) -> bool>,
     ) -> Result<Events<'static>, parse::Error> {
-        from_bytes(input, &|e| e.to_owned(), filter)
+        from_bytes(input, &|e| e.to_owned(), filter, false)
+    }
+
+    /// Like [`from_bytes_owned()`][Events::from_bytes_owned()], but also record the location of each key along with its value,
+    /// see [`from_bytes_with_key_spans()`][Events::from_bytes_with_key_spans()].
+    pub fn from_bytes_owned_with_key_spans<'a>(
+        input: &'a [u8],
+        filter: Option<fn(&Event<'a>) -> bool>,
+    ) -> Result<Events<'static>, parse::Error> {
+        from_bytes(input, &|e| e.to_owned(), filter, true)
     }
 }
 
@@ -241,7 +250,16 @@ impl<'a> Events<'a> {
     ///
     /// Use `filter` to only include those events for which it returns true.
     pub fn from_bytes(input: &'a [u8], filter: Option<fn(&Event<'a>) -> bool>) -> Result<Events<'a>, parse::Error> {
-        from_bytes(input, &std::convert::identity, filter)
+        from_bytes(input, &std::convert::identity, filter, false)
+    }
+
+    /// Like [`from_bytes()`][Self::from_bytes()], but also record the location of each key along with its value in
+    /// [`Section::key_spans`][parse::Section::key_spans], which is skipped otherwise as it's only needed by some tools.
+    pub fn from_bytes_with_key_spans(
+        input: &'a [u8],
+        filter: Option<fn(&Event<'a>) -> bool>,
+    ) -> Result<Events<'a>, parse::Error> {
+        from_bytes(input, &std::convert::identity, filter, true)
     }
 
     /// Attempt to zero-copy parse the provided `input` string.
@@ -290,36 +308,52 @@ fn from_bytes<'a, 'b>(
     input: &'a [u8],
     convert: &dyn Fn(Event<'a>) -> Event<'b>,
     filter: Option<fn(&Event<'a>) -> bool>,
+    key_spans: bool,
 ) -> Result<Events<'b>, parse::Error> {
     let mut header = None;
     let mut events = section::Events::default();
     let mut frontmatter = FrontMatterEvents::default();
     let mut sections = Vec::new();
-    parse::from_bytes(input, &mut |e: Event<'_>| match e {
-        Event::SectionHeader(next_header) => {
-            match header.take() {
-                None => {
-                    frontmatter = std::mem::take(&mut events).into_iter().collect();
-                }
-                Some(prev_header) => {
-                    sections.push(parse::Section {
-                        header: prev_header,
-                        events: std::mem::take(&mut events),
-                    });
+    let mut section_offsets = Vec::new();
+    let mut body = KeySpans::default();
+    let mut body_lens = Vec::new();
+    parse::nom::from_bytes_with_section_offsets(
+        input,
+        &mut |e: Event<'_>| match e {
+            Event::SectionHeader(next_header) => {
+                match header.take() {
+                    None => {
+                        frontmatter = std::mem::take(&mut events).into_iter().collect();
+                    }
+                    Some(prev_header) => {
+                        sections.push(parse::Section {
+                            header: prev_header,
+                            events: std::mem::take(&mut events),
+                            span: None,
+                            key_spans: std::mem::take(&mut body.spans),
+                        });
+                        body_lens.push(body.len);
+                    }
+                };
+                body = Default::default();
+                header = match convert(Event::SectionHeader(next_header)) {
+                    Event::SectionHeader(h) => h,
+                    _ => unreachable!("BUG: convert must not change the event type, just the lifetime"),
                 }
-            };
-            header = match convert(Event::SectionHeader(next_header)) {
-                Event::SectionHeader(h) => h,
-                _ => unreachable!("BUG: convert must not change the event type, just the lifetime"),
+                .into();
             }
-            .into();
-        }
-        event => {
-            if filter.map_or(true, |f| f(&event)) {
-                events.push(convert(event))
+            event => {
+                let keep = filter.map_or(true, |f| f(&event));
+                if key_spans {
+                    body.record(&event, keep.then_some(events.len()));
+                }
+                if keep {
+                    events.push(convert(event))
+                }
             }
-        }
-    })?;
+        },
+        &mut |offset| section_offsets.push(offset),
+    )?;
 
     match header {
         None => {
@@ -329,8 +363,102 @@ fn from_bytes<'a, 'b>(
             sections.push(parse::Section {
                 header: prev_header,
                 events: std::mem::take(&mut events),
+                span: None,
+                key_spans: body.spans,
             });
+            body_lens.push(body.len);
         }
     }
+    set_spans(input, &section_offsets, &body_lens, &mut sections);
     Ok(Events { frontmatter, sections })
 }
+
+/// The location of keys relative to the start of a section body, as the section's position isn't known while parsing it.
+#[derive(Default)]
+struct KeySpans {
+    /// The amount of bytes of the body seen so far.
+    len: usize,
+    /// The index of each key event along with the location of the key and its value, with only `start` and `end`
+    /// set, relative to the start of the body.
+    spans: Vec<(usize, parse::Span)>,
+    /// If true, the last key in `spans` didn't see its value yet.
+    value_pending: bool,
+}
+
+impl KeySpans {
+    /// Record the body `event`, which is kept at `event_index` among the events of its section, or is filtered out if `None`.
+    ///
+    /// Filtered events must be recorded as well, so all of the input is accounted for.
+    fn record(&mut self, event: &Event<'_>, event_index: Option<usize>) {
+        // All events in a section body are reproduced exactly by `Event::write_to()`, so this is their size in the input.
+        let len = match event {
+            Event::ValueNotDone(v) => v.len() + 1,
+            Event::Whitespace(v) | Event::Newline(v) | Event::Value(v) | Event::ValueDone(v) => v.len(),
+            Event::KeyValueSeparator => 1,
+            Event::SectionKey(key) => key.0.len(),
+            Event::Comment(comment) => 1 + comment.text.len(),
+            Event::SectionHeader(_) => unreachable!("headers aren't part of a section body"),
+        };
+        match event {
+            Event::SectionKey(_) => {
+                self.value_pending = event_index.is_some();
+                if let Some(event_index) = event_index {
+                    let span = parse::Span {
+                        start: self.len,
+                        end: self.len + len,
+                        start_line: 0,
+                        end_line: 0,
+                    };
+                    self.spans.push((event_index, span));
+                }
+            }
+            Event::KeyValueSeparator if self.value_pending => {
+                self.spans.last_mut().expect("key was seen").1.end = self.len + len;
+            }
+            Event::Value(value) | Event::ValueDone(value) if self.value_pending => {
+                // Implicit and empty values are empty, and we don't want to include the whitespace before them.
+                if !value.is_empty() {
+                    self.spans.last_mut().expect("key was seen").1.end = self.len + len;
+                }
+                self.value_pending = false;
+            }
+            _ => {}
+        }
+        self.len += len;
+    }
+}
+
+/// Assign a span to each of `sections` and their keys, which start at the respective position in `section_offsets`
+/// within `input`, with `body_lens` being the length of each section body.
+fn set_spans(input: &[u8], section_offsets: &[usize], body_lens: &[usize], sections: &mut [parse::Section<'_>]) {
+    let mut line = 1;
+    let mut counted_until = 0;
+    let mut line_at = |offset: usize| {
+        line += input[counted_until..offset].iter().filter(|b| **b == b'\n').count();
+        counted_until = offset;
+        line
+    };
+    for (idx, ((section, start), body_len)) in sections
+        .iter_mut()
+        .zip(section_offsets.iter().copied())
+        .zip(body_lens.iter().copied())
+        .enumerate()
+    {
+        let end = section_offsets.get(idx + 1).copied().unwrap_or(input.len());
+        let start_line = line_at(start);
+        // The header isn't necessarily reproduced exactly, so we measure the body from the end instead.
+        let body_start = end - body_len;
+        for (_, span) in &mut section.key_spans {
+            span.start += body_start;
+            span.end += body_start;
+            span.start_line = line_at(span.start);
+            span.end_line = line_at(span.end - 1);
+        }
+        section.span = Some(parse::Span {
+            start,
+            end,
+            start_line,
+            end_line: line_at(end - 1),
+        });
+    }
+}
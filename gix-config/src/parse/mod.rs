@@ -88,12 +88,47 @@ pub enum Event<'a> {
 
 /// A parsed section containing the header and the section events, typically
 /// comprising the keys and their values.
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+///
+/// Note that the location of the section and its keys isn't considered when comparing or hashing sections.
+#[derive(Clone, Debug)]
 pub struct Section<'a> {
     /// The section name and subsection name, if any.
     pub header: section::Header<'a>,
     /// The syntactic events found in this section.
     pub events: section::Events<'a>,
+    /// The location of the section in the input it was parsed from, or `None` if it wasn't parsed.
+    pub span: Option<Span>,
+    /// The index of each [`SectionKey`][Event::SectionKey] in `events` along with the location of the key and its value
+    /// in the input it was parsed from, in order of appearance.
+    ///
+    /// It's empty if the section wasn't parsed, or if key spans weren't requested with
+    /// [`Events::from_bytes_with_key_spans()`] or [`Events::from_bytes_owned_with_key_spans()`].
+    pub key_spans: Vec<(usize, Span)>,
+}
+
+/// The location of a [`Section`], or a key along with its value, in the input it was parsed from.
+///
+/// A section starts at its header and ends right before the next section header, or at the end of the input.
+/// Hence it includes all whitespace and comments that follow its last value.
+///
+/// A key starts at its name and ends after its value, which may span multiple lines, or after its name if it has no value.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Span {
+    /// The offset of the first byte, counting from the start of the input.
+    pub start: usize,
+    /// The offset one past the last byte.
+    pub end: usize,
+    /// The one-based number of the line containing the first byte.
+    pub start_line: usize,
+    /// The one-based number of the line containing the last byte.
+    pub end_line: usize,
+}
+
+impl Span {
+    /// Return the byte range of the span, for use as index into the input it was parsed from.
+    pub fn to_range(&self) -> std::ops::Range<usize> {
+        self.start..self.end
+    }
 }
 
 /// A parsed comment containing the comment marker and comment.
@@ -12,7 +12,17 @@ use winnow::{
 use crate::parse::{error::ParseNode, section, Comment, Error, Event};
 
 /// Attempt to zero-copy parse the provided bytes, passing results to `dispatch`.
-pub fn from_bytes<'i>(mut input: &'i [u8], dispatch: &mut dyn FnMut(Event<'i>)) -> Result<(), Error> {
+pub fn from_bytes<'i>(input: &'i [u8], dispatch: &mut dyn FnMut(Event<'i>)) -> Result<(), Error> {
+    from_bytes_with_section_offsets(input, dispatch, &mut |_| {})
+}
+
+/// Like [`from_bytes()`], but call `section_offset` with the offset of each section header into `input`
+/// right before it is passed to `dispatch`.
+pub(crate) fn from_bytes_with_section_offsets<'i>(
+    mut input: &'i [u8],
+    dispatch: &mut dyn FnMut(Event<'i>),
+    section_offset: &mut dyn FnMut(usize),
+) -> Result<(), Error> {
     let start = input.checkpoint();
 
     let bom = unicode_bom::Bom::from(input);
@@ -46,9 +56,19 @@ pub fn from_bytes<'i>(mut input: &'i [u8], dispatch: &mut dyn FnMut(Event<'i>))
 
     let mut node = ParseNode::SectionHeader;
 
-    let res = repeat(1.., |i: &mut &'i [u8]| section(i, &mut node, dispatch))
-        .map(|()| ())
-        .parse_next(&mut input);
+    let res = repeat(1.., |i: &mut &'i [u8]| {
+        let offset = i.offset_from(&start);
+        let mut is_header = true;
+        section(i, &mut node, &mut |event| {
+            // The header is dispatched first, and only if it could be parsed.
+            if std::mem::take(&mut is_header) {
+                section_offset(offset);
+            }
+            dispatch(event)
+        })
+    })
+    .map(|()| ())
+    .parse_next(&mut input);
     res.map_err(|_| {
         let newlines = newlines_from(input, start);
         Error {
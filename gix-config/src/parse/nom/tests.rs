@@ -196,6 +196,8 @@ mod section {
                         _ => unreachable!("unexpected"),
                     },
                     events,
+                    span: None,
+                    key_spans: Vec::new(),
                 },
             )
         })
@@ -218,6 +220,8 @@ mod section {
                     newline_custom_event("\r\n")
                 ]
                 .into(),
+                span: None,
+                key_spans: Vec::new(),
             }),
         );
     }
@@ -239,6 +243,8 @@ mod section {
                     newline_custom_event("\r\n")
                 ]
                 .into(),
+                span: None,
+                key_spans: Vec::new(),
             }),
         );
         assert_eq!(
@@ -255,6 +261,8 @@ mod section {
                     newline_custom_event("\r\n")
                 ]
                 .into(),
+                span: None,
+                key_spans: Vec::new(),
             }),
         );
     }
@@ -266,7 +274,9 @@ mod section {
             section(b"[test]", &mut node).unwrap(),
             fully_consumed(Section {
                 header: parsed_section_header("test", None),
-                events: Default::default()
+                events: Default::default(),
+                span: None,
+                key_spans: Vec::new(),
             }),
         );
     }
@@ -302,7 +312,9 @@ mod section {
                     whitespace_event(" "),
                     value_event("\"lol\"")
                 ]
-                .into()
+                .into(),
+                span: None,
+                key_spans: Vec::new(),
             })
         );
     }
@@ -321,7 +333,9 @@ mod section {
                     Event::KeyValueSeparator,
                     value_event(""),
                 ]
-                .into()
+                .into(),
+                span: None,
+                key_spans: Vec::new(),
             })
         );
 
@@ -337,7 +351,9 @@ mod section {
                     value_event(""),
                     newline_event(),
                 ]
-                .into()
+                .into(),
+                span: None,
+                key_spans: Vec::new(),
             })
         );
     }
@@ -374,7 +390,9 @@ mod section {
                     whitespace_event(" "),
                     value_event("\"lol\"")
                 ]
-                .into()
+                .into(),
+                span: None,
+                key_spans: Vec::new(),
             })
         );
     }
@@ -386,7 +404,9 @@ mod section {
             section(b"[hello] c", &mut node).unwrap(),
             fully_consumed(Section {
                 header: parsed_section_header("hello", None),
-                events: vec![whitespace_event(" "), name_event("c"), value_event("")].into()
+                events: vec![whitespace_event(" "), name_event("c"), value_event("")].into(),
+                span: None,
+                key_spans: Vec::new(),
             })
         );
 
@@ -402,7 +422,9 @@ mod section {
                     name_event("d"),
                     value_event("")
                 ]
-                .into()
+                .into(),
+                span: None,
+                key_spans: Vec::new(),
             })
         );
     }
@@ -445,7 +467,9 @@ mod section {
                     whitespace_event(" "),
                     value_event("d"),
                 ]
-                .into()
+                .into(),
+                span: None,
+                key_spans: Vec::new(),
             })
         );
     }
@@ -472,7 +496,9 @@ mod section {
                     whitespace_event(" "),
                     comment_event('#', " \"b\t ; c"),
                 ]
-                .into()
+                .into(),
+                span: None,
+                key_spans: Vec::new(),
             })
         );
     }
@@ -494,7 +520,9 @@ mod section {
                     value_done_event(";\""),
                     comment_event(';', "a"),
                 ]
-                .into()
+                .into(),
+                span: None,
+                key_spans: Vec::new(),
             })
         );
     }
@@ -512,7 +540,9 @@ mod section {
                     value_event(""),
                     comment_event('#', "world"),
                 ]
-                .into()
+                .into(),
+                span: None,
+                key_spans: Vec::new(),
             })
         );
     }
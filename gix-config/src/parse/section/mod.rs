@@ -34,10 +34,41 @@ impl Section<'_> {
         Section {
             header: self.header.to_owned(),
             events: self.events.iter().map(Event::to_owned).collect(),
+            span: self.span,
+            key_spans: self.key_spans.clone(),
         }
     }
 }
 
+impl PartialEq for Section<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.header == other.header && self.events == other.events
+    }
+}
+
+impl Eq for Section<'_> {}
+
+impl PartialOrd for Section<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Section<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.header
+            .cmp(&other.header)
+            .then_with(|| self.events.cmp(&other.events))
+    }
+}
+
+impl std::hash::Hash for Section<'_> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.header.hash(state);
+        self.events.hash(state);
+    }
+}
+
 impl Display for Section<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.header)?;
@@ -1,6 +1,7 @@
 pub use gix_testtools::Result;
 
 mod file;
+mod filter;
 mod parse;
 mod source;
 mod value;
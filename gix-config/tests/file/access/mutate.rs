@@ -35,6 +35,7 @@ mod remove_section {
         assert_eq!(removed.header().name(), "core");
         assert_eq!(removed.header().subsection_name(), None);
         assert_eq!(file.sections().count(), 1);
+        assert!(file.section_mut("core", None).is_err(), "it can't be found anymore");
 
         let removed = file.remove_section("core", Some("name".into())).expect("found");
         assert_eq!(removed.header().name(), "core");
@@ -49,7 +50,9 @@ mod remove_section {
 mod rename_section {
     use std::{borrow::Cow, convert::TryFrom};
 
-    use gix_config::{file::rename_section, parse::section};
+    use gix_config::{file::rename_section, lookup, parse::section};
+
+    use crate::file::cow_str;
 
     #[test]
     fn section_renaming_validates_new_name() {
@@ -66,6 +69,111 @@ mod rename_section {
             ))
         ));
     }
+
+    #[test]
+    fn renamed_section_is_found_by_its_new_name_only() -> crate::Result {
+        let mut file = gix_config::File::try_from("[core]\n  a = b # comment\n[other]\n  c = d\n")?;
+        file.rename_section("core", None, "remote", Some(Cow::Borrowed("with \"quote\"".into())))?;
+
+        assert_eq!(file.string_by_key("core.a"), None, "the old name is gone");
+        assert!(file.section("core", None).is_err());
+        assert_eq!(
+            file.string_by_key("remote.with \"quote\".a").as_deref(),
+            Some("b".into())
+        );
+        assert_eq!(
+            file.to_string(),
+            "[remote \"with \\\"quote\\\"\"]\n  a = b # comment\n[other]\n  c = d\n",
+            "only the header changes, body and position are retained"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn the_old_name_can_be_looked_up_after_renaming() -> crate::Result {
+        let mut file = gix_config::File::try_from("[a]\n  v = 1\n[x \"sub\"]\n  v = 2\n")?;
+        file.rename_section("a", None, "b", None)?;
+        file.rename_section("x", Some("sub".into()), "y", None)?;
+
+        assert!(matches!(
+            file.section_mut("a", None),
+            Err(lookup::existing::Error::SectionMissing)
+        ));
+        assert!(matches!(
+            file.section_mut("x", Some("sub".into())),
+            Err(lookup::existing::Error::SectionMissing)
+        ));
+        assert!(file.section("a", None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn renaming_twice_from_the_same_old_name_fails() -> crate::Result {
+        let mut file = gix_config::File::try_from("[a]\n  v = 1\n")?;
+        file.rename_section("a", None, "b", None)?;
+
+        assert!(matches!(
+            file.rename_section("a", None, "c", None),
+            Err(rename_section::Error::Lookup(lookup::existing::Error::SectionMissing))
+        ));
+        assert!(matches!(
+            file.rename_section_filter_or_error("a", None, "c", None, &mut |_| true),
+            Err(rename_section::Error::Lookup(lookup::existing::Error::SectionMissing))
+        ));
+        assert_eq!(file.to_string(), "[b]\n  v = 1\n", "nothing else changed");
+        Ok(())
+    }
+
+    #[test]
+    fn renaming_into_an_existing_name_merges_values_in_order() -> crate::Result {
+        let mut file = gix_config::File::try_from("[a]\n  v = 1\n[b]\n  v = 2\n[a]\n  v = 3\n")?;
+        let id = file.sections().nth(1).expect("three sections").id();
+        file.rename_section_filter("b", None, "a", None, &mut |meta| meta.level == 0)?;
+
+        assert_eq!(
+            file.strings_by_key("a.v").expect("present"),
+            vec![cow_str("1"), cow_str("2"), cow_str("3")],
+            "the renamed section keeps its position"
+        );
+        assert_eq!(
+            file.sections().nth(1).map(gix_config::file::Section::id),
+            Some(id),
+            "the section keeps its id"
+        );
+        assert!(file.section("b", None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn renaming_into_an_existing_name_can_fail_instead() -> crate::Result {
+        let input = "[a]\n  v = 1\n[b \"sub\"]\n  v = 2\n";
+        let mut file = gix_config::File::try_from(input)?;
+        let err = file
+            .rename_section_or_error("a", None, "b", Some(Cow::Borrowed("sub".into())))
+            .unwrap_err();
+        assert!(matches!(err, rename_section::Error::Exists { .. }));
+        assert_eq!(err.to_string(), "Section [b \"sub\"] already exists");
+
+        assert!(
+            matches!(
+                file.rename_section_filter_or_error("b", Some("sub".into()), "A", None, &mut |_| true),
+                Err(rename_section::Error::Exists { .. })
+            ),
+            "names are compared case-insensitively"
+        );
+        assert_eq!(file.to_string(), input, "nothing was changed");
+
+        file.rename_section_or_error("a", None, "a", None)?;
+        assert_eq!(file.to_string(), input, "renaming a section to its own name is fine");
+
+        file.rename_section_filter_or_error("b", Some("sub".into()), "c", None, &mut |_| true)?;
+        assert_eq!(
+            file.string_by_key("c.v").as_deref(),
+            Some("2".into()),
+            "the new name is free"
+        );
+        Ok(())
+    }
 }
 mod set_meta {
     use gix_config::file;
@@ -96,3 +204,100 @@ mod set_meta {
         Ok(())
     }
 }
+
+mod new_section_with_policy {
+    use std::{borrow::Cow, convert::TryFrom};
+
+    use gix_config::file::InsertionPolicy;
+
+    use crate::file::cow_str;
+
+    const INPUT: &str = "[core]\n\ta = 1\n[remote \"a\"]\n\turl = a\n[core]\n\ta = 2\n[user]\n";
+
+    fn new_section(policy: InsertionPolicy) -> crate::Result<gix_config::File<'static>> {
+        let mut file = gix_config::File::try_from(INPUT)?;
+        file.new_section_with_policy("remote", Some(Cow::Borrowed("b".into())), policy)?
+            .push("url".try_into()?, Some("b".into()));
+        Ok(file)
+    }
+
+    #[test]
+    fn append_to_end() -> crate::Result {
+        assert_eq!(
+            new_section(InsertionPolicy::AppendToEnd)?.to_string(),
+            format!("{INPUT}[remote \"b\"]\n\turl = b\n")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn after_last_same_named() -> crate::Result {
+        assert_eq!(
+            new_section(InsertionPolicy::AfterLastSameNamed)?.to_string(),
+            "[core]\n\ta = 1\n[remote \"a\"]\n\turl = a\n[remote \"b\"]\n\turl = b\n[core]\n\ta = 2\n[user]\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn before_first_same_named() -> crate::Result {
+        assert_eq!(
+            new_section(InsertionPolicy::BeforeFirstSameNamed)?.to_string(),
+            "[core]\n\ta = 1\n[remote \"b\"]\n\turl = b\n[remote \"a\"]\n\turl = a\n[core]\n\ta = 2\n[user]\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn without_same_named_section_it_appends() -> crate::Result {
+        for policy in [
+            InsertionPolicy::AfterLastSameNamed,
+            InsertionPolicy::BeforeFirstSameNamed,
+        ] {
+            let mut file = gix_config::File::try_from(INPUT)?;
+            file.new_section_with_policy("branch", None, policy)?;
+            assert_eq!(file.to_string(), format!("{INPUT}[branch]\n"));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn precedence_follows_the_position_of_the_new_section() -> crate::Result {
+        let mut file = gix_config::File::try_from(INPUT)?;
+        file.new_section_with_policy("core", None, InsertionPolicy::BeforeFirstSameNamed)?
+            .push("a".try_into()?, Some("0".into()));
+        assert_eq!(
+            file.integer_by_key("core.a").transpose()?,
+            Some(2),
+            "the last section still wins"
+        );
+        assert_eq!(
+            file.strings_by_key("core.a").expect("present"),
+            vec![cow_str("0"), cow_str("1"), cow_str("2")],
+            "all values are returned in order of appearance"
+        );
+
+        file.new_section_with_policy("core", None, InsertionPolicy::AfterLastSameNamed)?
+            .push("a".try_into()?, Some("3".into()));
+        assert_eq!(file.integer_by_key("core.a").transpose()?, Some(3));
+        Ok(())
+    }
+
+    #[test]
+    fn section_mut_or_create_new_filter_with_policy() -> crate::Result {
+        let mut file = gix_config::File::try_from(INPUT)?;
+        file.section_mut_or_create_new_filter_with_policy(
+            "remote",
+            Some("a".into()),
+            &mut |_| false,
+            InsertionPolicy::AfterLastSameNamed,
+        )?
+        .push("url".try_into()?, Some("other".into()));
+        assert_eq!(
+            file.to_string(),
+            "[core]\n\ta = 1\n[remote \"a\"]\n\turl = a\n[remote \"a\"]\n\turl = other\n[core]\n\ta = 2\n[user]\n",
+            "filtered sections are never returned, but new ones are created according to policy"
+        );
+        Ok(())
+    }
+}
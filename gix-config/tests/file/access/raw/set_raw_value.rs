@@ -65,3 +65,21 @@ fn non_existing_values_cannot_be_set() -> crate::Result {
     );
     Ok(())
 }
+
+#[test]
+fn subsections_may_contain_dots() -> crate::Result {
+    let mut file = gix_config::File::default();
+    file.set_raw_value("url", Some("https://example.com/a.b".into()), "insteadOf", "ex:")?;
+
+    assert_eq!(
+        file.string("url", Some("https://example.com/a.b".into()), "insteadOf")
+            .expect("present")
+            .as_ref(),
+        "ex:"
+    );
+    assert_eq!(
+        file.to_string(),
+        "[url \"https://example.com/a.b\"]\n\tinsteadOf = ex:\n"
+    );
+    Ok(())
+}
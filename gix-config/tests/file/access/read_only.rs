@@ -130,6 +130,24 @@ fn get_value_for_all_provided_values() -> crate::Result {
             }
         );
 
+        assert_eq!(
+            config.color("core", None, "color").expect("present")?,
+            config.value::<Color>("core", None, "color")?
+        );
+        assert_eq!(
+            config
+                .color_by_key("core.color")
+                .expect("present")?
+                .to_ansi_escape_sequence(),
+            "\x1b[1;92;41m"
+        );
+        assert_eq!(
+            config.color("core", None, "empty-equals").expect("present")?,
+            Color::default(),
+            "empty values mean 'no color'"
+        );
+        assert!(config.color("core", None, "other").expect("present").is_err());
+
         {
             let string = config.value::<Cow<'_, BStr>>("core", None, "other")?;
             assert_eq!(string, cow_str("hello world"));
@@ -322,6 +340,55 @@ fn unknown_section() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn conversion_errors_chain_their_cause() -> crate::Result {
+    let config = File::try_from("[core]\n\ta = 123123123123123123123123\n")?;
+    let err = config.value::<Integer>("core", None, "a").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "The value could not be converted into the requested type"
+    );
+    let source = std::error::Error::source(&err).expect("the conversion error is the source");
+    assert_eq!(
+        source.to_string(),
+        "Could not decode '123123123123123123123123': Integers needs to be positive or negative numbers which may have a suffix like 1k, 42, or 50G"
+    );
+
+    let err = config.value::<Integer>("core", None, "missing").unwrap_err();
+    assert!(matches!(
+        err,
+        gix_config::lookup::Error::ValueMissing(gix_config::lookup::existing::Error::KeyMissing)
+    ));
+    assert_eq!(err.to_string(), "The key does not exist in the requested section");
+    Ok(())
+}
+
+#[test]
+fn introspection() -> crate::Result {
+    let config = File::default();
+    assert!(config.is_empty());
+    assert!(config.is_void());
+    assert_eq!(config.num_sections(), 0);
+    assert_eq!(config.num_values(), 0);
+
+    let config = File::try_from("# comment only\n")?;
+    assert!(!config.is_empty(), "front-matter would be written");
+    assert_eq!(config.num_sections(), 0);
+
+    let mut config = File::try_from("[core]\n[remote \"origin\"]\n\turl = a\n\tfetch = b\n[empty]")?;
+    assert!(!config.is_empty());
+    assert!(!config.is_void());
+    assert_eq!(config.num_sections(), 3, "empty sections are counted as well");
+    assert_eq!(config.num_values(), 2);
+
+    for id in config.sections_and_ids().map(|(_, id)| id).collect::<Vec<_>>() {
+        config.remove_section_by_id(id);
+    }
+    assert!(config.is_empty());
+    assert_eq!(config.to_bstring(), "", "empty files produce no output");
+    Ok(())
+}
+
 #[test]
 fn multi_line_value_plain() {
     let config = r#"
@@ -429,3 +496,68 @@ fn overrides_with_implicit_booleans_work_across_sections() {
         "empty implicit booleans "
     );
 }
+
+#[test]
+fn section_spans_point_into_the_original_input() -> crate::Result {
+    let input = "[core]\n\ta = b\n[remote \"origin\"]\n\turl = c\n";
+    let mut config = File::try_from(input)?;
+    let span = config
+        .section("remote", Some("origin".into()))?
+        .span()
+        .expect("parsed sections have a span");
+    assert_eq!(&input[span.to_range()], "[remote \"origin\"]\n\turl = c\n");
+    assert_eq!((span.start_line, span.end_line), (3, 4));
+
+    config.set_raw_value("core", None, "a", "changed")?;
+    assert_eq!(
+        config.section("core", None)?.span().map(|span| span.to_range()),
+        Some(0..14),
+        "modifications don't affect the span"
+    );
+
+    let section = config.new_section("new", None)?;
+    assert_eq!(section.span(), None, "new sections were never parsed");
+    Ok(())
+}
+
+#[test]
+fn value_spans_point_to_the_definition_of_values() -> crate::Result {
+    let input = "[core]\n\ta = 1\n\tA = 2\n\tflag\n[core]\n\tb = 3\n[core]\n\ta\n";
+    assert_eq!(
+        File::try_from(input)?.raw_value_span("core", None, "a")?,
+        None,
+        "the location of keys isn't recorded by default"
+    );
+
+    let mut config = crate::file::with_key_spans(input);
+    let span = config.raw_value_span("core", None, "a")?.expect("parsed");
+    assert_eq!(
+        (&input[span.to_range()], span.start_line),
+        ("A = 2", 3),
+        "just like raw_value(), the last value wins, while keys are case-insensitive and implicit values are skipped"
+    );
+    assert_eq!(config.raw_value("core", None, "a")?.as_ref(), "2");
+
+    let span = config.section("core", None)?.key_span("a").expect("parsed");
+    assert_eq!(&input[span.to_range()], "a", "the last section has an implicit value");
+
+    assert!(config.raw_value_span("core", None, "missing").is_err());
+    assert!(
+        config
+            .raw_value_span_filter("core", None, "b", &mut |_| true)?
+            .is_some(),
+        "it finds values in other sections"
+    );
+    assert!(
+        config.raw_value_span_filter("core", None, "b", &mut |_| false).is_err(),
+        "the filter is respected"
+    );
+
+    config.set_raw_value("new", None, "key", "value")?;
+    assert_eq!(
+        config.raw_value_span("new", None, "key")?,
+        None,
+        "values that weren't parsed have no span"
+    );
+    Ok(())
+}
@@ -7,6 +7,18 @@ pub fn cow_str(s: &str) -> Cow<'_, BStr> {
     Cow::Borrowed(s.as_bytes().as_bstr())
 }
 
+pub fn with_key_spans(input: &str) -> File<'_> {
+    File::from_bytes_no_includes(
+        input.as_bytes(),
+        gix_config::file::Metadata::api(),
+        gix_config::file::init::Options {
+            key_spans: true,
+            ..Default::default()
+        },
+    )
+    .expect("valid input")
+}
+
 #[test]
 fn size_in_memory() {
     let actual = std::mem::size_of::<gix_config::File<'_>>();
@@ -116,6 +116,21 @@ mod set {
         );
         Ok(())
     }
+
+    #[test]
+    fn changed_values_lose_their_span() -> crate::Result {
+        let input = init_config().to_string();
+        let mut config = crate::file::with_key_spans(&input);
+        config.raw_values_mut("core", None, "a")?.set_string_at(1, "Hello");
+        assert_eq!(
+            config.raw_value_span("core", None, "a")?.map(|span| span.start_line),
+            Some(5),
+            "the last value is still the parsed one"
+        );
+        config.raw_values_mut("core", None, "a")?.set_all("Hello");
+        assert_eq!(config.raw_value_span("core", None, "a")?, None);
+        Ok(())
+    }
 }
 
 mod delete {
@@ -139,6 +154,24 @@ mod delete {
         Ok(())
     }
 
+    #[test]
+    fn remaining_values_keep_their_span() -> crate::Result {
+        let input = init_config().to_string();
+        let mut config = crate::file::with_key_spans(&input);
+        config.raw_values_mut("core", None, "a")?.delete(2);
+        assert_eq!(
+            config.raw_value_span("core", None, "a")?.map(|span| span.start_line),
+            Some(4),
+            "the previous value is now the last one"
+        );
+        config.raw_values_mut("core", None, "a")?.delete_all();
+        assert!(
+            config.raw_value_span("core", None, "a").is_err(),
+            "there is no value anymore"
+        );
+        Ok(())
+    }
+
     #[test]
     fn all() -> crate::Result {
         let mut config = init_config();
@@ -259,6 +259,61 @@ mod set_leading_whitespace {
     }
 }
 
+mod key_span {
+    use std::convert::TryInto;
+
+    use crate::file::with_key_spans;
+
+    #[test]
+    fn pushed_values_have_no_span() -> crate::Result {
+        let input = "[a]\n  k = 1\n  other = 2\n";
+        let mut config = with_key_spans(input);
+        config.section_mut("a", None)?.push("k".try_into()?, Some("2".into()));
+
+        assert_eq!(config.raw_value("a", None, "k")?.as_ref(), "2");
+        assert_eq!(
+            config.raw_value_span("a", None, "k")?,
+            None,
+            "the value that is returned wasn't parsed"
+        );
+        let span = config.raw_value_span("a", None, "other")?.expect("parsed");
+        assert_eq!(&input[span.to_range()], "other = 2", "unchanged keys keep their span");
+        Ok(())
+    }
+
+    #[test]
+    fn set_remove_and_pop_forget_the_span_of_changed_keys_only() -> crate::Result {
+        let input = "[a]\n  x = 1\n  y = 2 \\\n  continued\n  z = 3\n  last\n";
+        let mut config = with_key_spans(input);
+        let key_span = |config: &gix_config::File<'_>, key: &str| {
+            config
+                .section("a", None)
+                .expect("present")
+                .key_span(key)
+                .map(|span| &input[span.to_range()])
+        };
+
+        config.section_mut("a", None)?.set("y".try_into()?, "new".into());
+        assert_eq!(key_span(&config, "y"), None, "the value changed");
+        assert_eq!(key_span(&config, "x"), Some("x = 1"));
+        assert_eq!(
+            key_span(&config, "z"),
+            Some("z = 3"),
+            "keys after the changed one are still found"
+        );
+
+        config.section_mut("a", None)?.remove("x");
+        assert_eq!(key_span(&config, "x"), None, "the key is gone");
+        assert_eq!(key_span(&config, "z"), Some("z = 3"));
+        assert_eq!(key_span(&config, "last"), Some("last"));
+
+        config.section_mut("a", None)?.pop();
+        assert_eq!(key_span(&config, "last"), None, "the key is gone");
+        assert_eq!(key_span(&config, "z"), Some("z = 3"));
+        Ok(())
+    }
+}
+
 fn multi_value_section() -> gix_config::File<'static> {
     r"
         [a]
@@ -149,6 +149,20 @@ mod set_string {
         );
         Ok(())
     }
+
+    #[test]
+    fn changed_values_lose_their_span() -> crate::Result {
+        let input = init_config().to_string();
+        let mut config = crate::file::with_key_spans(&input);
+        config.raw_value_mut("core", None, "c")?.set_string("changed");
+        assert_eq!(config.raw_value_span("core", None, "c")?, None);
+        assert_eq!(
+            config.raw_value_span("core", None, "e")?.map(|span| span.start_line),
+            Some(5),
+            "unchanged values keep their span"
+        );
+        Ok(())
+    }
 }
 
 mod delete {
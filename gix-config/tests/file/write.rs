@@ -109,6 +109,51 @@ fn complex_lossless_roundtrip() {
     );
 }
 
+#[test]
+fn lossless_roundtrip_of_fixtures() -> crate::Result {
+    for name in ["multi-core.txt", "repo-config.crlf"] {
+        let input = std::fs::read(gix_testtools::fixture_path_standalone(name))?;
+        let config = gix_config::File::from_bytes_no_includes(&input, Metadata::api(), Default::default())?;
+        let mut expected = input.clone();
+        if !expected.ends_with(b"\n") {
+            expected.push(b'\n');
+        }
+        assert_eq!(
+            config.to_bstring(),
+            expected,
+            "{name}: unmodified files are reproduced byte-for-byte, but will always end with a newline"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn edits_only_affect_the_targeted_bytes() -> crate::Result {
+    let input = "; a comment\n[core]\n\trepositoryformatversion = 0\n\tbare = false ; inline\n\tlogallrefupdates = true\n\n[alias]\n\tsave = \"!f() { \\\n\t  git status; \\\n\t}; f\"\n";
+    let mut config = gix_config::File::try_from(input)?;
+    config.set_raw_value("core", None, "bare", "true")?;
+    assert_eq!(
+        config.to_bstring(),
+        input.replace("bare = false", "bare = true"),
+        "only the value itself changes, whitespace, comments and continuations remain untouched"
+    );
+    Ok(())
+}
+
+#[test]
+fn missing_trailing_newline_is_added() -> crate::Result {
+    let config = gix_config::File::try_from("[a]\n\tb = c")?;
+    assert_eq!(config.to_bstring(), "[a]\n\tb = c\n");
+
+    let config = gix_config::File::try_from("; c")?;
+    assert_eq!(
+        config.to_bstring(),
+        "; c",
+        "without sections, front-matter is reproduced as is"
+    );
+    Ok(())
+}
+
 mod to_filter {
     use bstr::ByteSlice;
     use gix_config::file::Metadata;
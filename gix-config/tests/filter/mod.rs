@@ -0,0 +1,63 @@
+use gix_config::{
+    file::Metadata,
+    filter::{by_max_level, by_source},
+    File, Source,
+};
+
+use crate::file::cow_str;
+
+fn config() -> crate::Result<File<'static>> {
+    let mut config = File::from_bytes_owned(
+        &mut b"[core]\nkey = user".to_vec(),
+        Metadata::from(Source::User),
+        Default::default(),
+    )?;
+    config.append(File::from_bytes_owned(
+        &mut b"[core]\nkey = local".to_vec(),
+        Metadata::from(Source::Local),
+        Default::default(),
+    )?);
+    config.append(File::from_bytes_owned(
+        &mut b"[core]\nkey = included".to_vec(),
+        Metadata {
+            level: 1,
+            ..Source::Local.into()
+        },
+        Default::default(),
+    )?);
+    Ok(config)
+}
+
+#[test]
+fn source() -> crate::Result {
+    let config = config()?;
+    assert_eq!(
+        config.string_filter_by_key("core.key", &mut by_source(Source::User)),
+        Some(cow_str("user"))
+    );
+    assert_eq!(
+        config.strings_filter_by_key("core.key", &mut by_source(Source::Local)),
+        Some(vec![cow_str("local"), cow_str("included")]),
+        "included files inherit the source of the including file"
+    );
+    assert_eq!(
+        config.string_filter_by_key("core.key", &mut by_source(Source::System)),
+        None
+    );
+    Ok(())
+}
+
+#[test]
+fn max_level() -> crate::Result {
+    let config = config()?;
+    assert_eq!(
+        config.string_filter_by_key("core.key", &mut by_max_level(0)),
+        Some(cow_str("local")),
+        "values from includes are ignored"
+    );
+    assert_eq!(
+        config.string_filter_by_key("core.key", &mut by_max_level(1)),
+        Some(cow_str("included"))
+    );
+    Ok(())
+}
@@ -174,4 +174,124 @@ fn skips_bom() {
         Events::from_bytes_owned(bytes, None),
         Events::from_bytes_owned(bytes_with_gb18030_bom.as_bytes(), None)
     );
+
+    let span = |input: &[u8]| {
+        Events::from_bytes(input, None).unwrap().sections[0]
+            .span
+            .expect("parsed")
+    };
+    assert_eq!(
+        span(bytes_with_gb18030_bom.as_bytes()).start,
+        span(bytes).start + 3,
+        "spans are relative to the original input, including the BOM"
+    );
+}
+
+#[test]
+fn section_spans() -> crate::Result {
+    let input = "; frontmatter\n[core]\n  a = 1\n\n  ; trailing comment\n[remote \"origin\"] url = b\n  fetch = c \\\n  d\n[empty]";
+    let events = Events::from_bytes(input.as_bytes(), None)?;
+    let spans: Vec<_> = events
+        .sections
+        .iter()
+        .map(|section| section.span.expect("parsed sections have spans"))
+        .collect();
+
+    assert_eq!(
+        spans.iter().map(|span| &input[span.to_range()]).collect::<Vec<_>>(),
+        [
+            "[core]\n  a = 1\n\n  ; trailing comment\n",
+            "[remote \"origin\"] url = b\n  fetch = c \\\n  d\n",
+            "[empty]"
+        ],
+        "sections span from their header to the next one, or the end of input"
+    );
+    assert_eq!(
+        spans
+            .iter()
+            .map(|span| (span.start_line, span.end_line))
+            .collect::<Vec<_>>(),
+        [(2, 5), (6, 8), (9, 9)],
+        "line numbers are one-based and inclusive"
+    );
+
+    let filtered = Events::from_bytes(input.as_bytes(), Some(|e| !matches!(e, Event::Comment(_))))?;
+    assert_eq!(
+        filtered.sections.iter().map(|s| s.span).collect::<Vec<_>>(),
+        events.sections.iter().map(|s| s.span).collect::<Vec<_>>(),
+        "filtering events doesn't affect spans"
+    );
+    assert_eq!(
+        Events::from_bytes_owned(input.as_bytes(), None)?.sections[1].span,
+        Some(spans[1])
+    );
+
+    assert!(
+        events.sections.iter().all(|section| section.key_spans.is_empty()),
+        "the location of keys is only recorded on request"
+    );
+    let events = Events::from_bytes_with_key_spans(input.as_bytes(), None)?;
+    let key_spans = |events: &Events<'_>| {
+        events
+            .sections
+            .iter()
+            .flat_map(|section| {
+                section
+                    .key_spans
+                    .iter()
+                    .map(|(event_index, span)| match &section.events[*event_index] {
+                        Event::SectionKey(key) => {
+                            (key.to_string(), &input[span.to_range()], span.start_line, span.end_line)
+                        }
+                        _ => unreachable!("key spans point to keys"),
+                    })
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        key_spans(&events),
+        [
+            ("a".into(), "a = 1", 3, 3),
+            ("url".into(), "url = b", 6, 6),
+            ("fetch".into(), "fetch = c \\\n  d", 7, 8)
+        ],
+        "keys span their values, including continuation lines"
+    );
+    let filtered = Events::from_bytes_with_key_spans(input.as_bytes(), Some(|e| !matches!(e, Event::Whitespace(_))))?;
+    assert_eq!(
+        key_spans(&Events::from_bytes_owned_with_key_spans(input.as_bytes(), None)?),
+        key_spans(&events)
+    );
+    assert_eq!(
+        key_spans(&filtered),
+        key_spans(&events),
+        "filtering events doesn't affect key spans, but changes their event index"
+    );
+    Ok(())
+}
+
+#[test]
+fn key_spans_with_implicit_values_and_escaped_headers() -> crate::Result {
+    let input = "[a \"with \\\"quote\\\" and unnecessary \\cescape\"]\r\n\tflag ; comment\r\n\tempty =\r\n\tk=v";
+    let events = Events::from_bytes_with_key_spans(input.as_bytes(), None)?;
+    let section = &events.sections[0];
+    assert_eq!(
+        section
+            .key_spans
+            .iter()
+            .map(|(event_index, span)| (*event_index, &input[span.to_range()], span.start_line))
+            .collect::<Vec<_>>(),
+        [(2, "flag", 2), (8, "empty =", 3), (14, "k=v", 4)],
+        "implicit values span only their key, and escapes in headers don't affect positions"
+    );
+    assert!(section
+        .key_spans
+        .iter()
+        .all(|(event_index, _)| matches!(section.events[*event_index], Event::SectionKey(_))));
+    assert_ne!(
+        section.header.to_bstring().len(),
+        input.find('\r').expect("present"),
+        "the header isn't reproduced exactly"
+    );
+    Ok(())
 }
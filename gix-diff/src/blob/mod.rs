@@ -11,6 +11,41 @@ pub mod pipeline;
 ///
 pub mod platform;
 
+pub mod unified_diff;
+pub use unified_diff::UnifiedDiff;
+
+/// The amount of bytes at the beginning of a blob in which [`is_binary()`] looks for a NUL byte, just like `git` does.
+pub const BINARY_DETECTION_LIMIT: usize = 8000;
+
+/// Return `true` if `data` is considered binary, which is the case if there is a NUL byte within its
+/// first [`BINARY_DETECTION_LIMIT`] bytes.
+///
+/// This is the same heuristic `git` uses to decide if a blob can be diffed as text, unless a [`Driver`] says otherwise.
+pub fn is_binary(data: &[u8]) -> bool {
+    data[..data.len().min(BINARY_DETECTION_LIMIT)].contains(&0)
+}
+
+/// Like [`is_binary()`], but reads no more than [`BINARY_DETECTION_LIMIT`] bytes from `read` to decide,
+/// which is useful to avoid loading large blobs entirely.
+///
+/// Reading stops at the first NUL byte, which leaves the remaining bytes in `read` unconsumed.
+pub fn is_binary_stream(read: impl std::io::Read) -> std::io::Result<bool> {
+    let mut read = read.take(BINARY_DETECTION_LIMIT as u64);
+    let mut buf = [0; 1024];
+    loop {
+        match std::io::Read::read(&mut read, &mut buf) {
+            Ok(0) => return Ok(false),
+            Ok(n) => {
+                if buf[..n].contains(&0) {
+                    return Ok(true);
+                }
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+}
+
 /// Information about the diff performed to detect similarity.
 #[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
 pub struct DiffLineStats {
@@ -352,7 +352,7 @@ impl Pipeline {
                                                 })?;
                                             }
 
-                                            Some(if is_binary.unwrap_or_else(|| is_binary_buf(out)) {
+                                            Some(if is_binary.unwrap_or_else(|| crate::blob::is_binary(out)) {
                                                 let size = out.len() as u64;
                                                 out.clear();
                                                 Data::Binary { size }
@@ -472,7 +472,7 @@ impl Pipeline {
                         }
 
                         if driver.map_or(true, |d| d.binary_to_text_command.is_none())
-                            && is_binary.unwrap_or_else(|| is_binary_buf(out))
+                            && is_binary.unwrap_or_else(|| crate::blob::is_binary(out))
                         {
                             let size = out.len() as u64;
                             out.clear();
@@ -489,11 +489,6 @@ impl Pipeline {
     }
 }
 
-fn is_binary_buf(buf: &[u8]) -> bool {
-    let buf = &buf[..buf.len().min(8000)];
-    buf.contains(&0)
-}
-
 fn none_if_missing<T>(res: std::io::Result<T>) -> std::io::Result<Option<T>> {
     match res {
         Ok(data) => Ok(Some(data)),
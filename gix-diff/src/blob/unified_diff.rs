@@ -0,0 +1,214 @@
+//! Facilities to produce the unified diff format, i.e. textual patches with `@@` hunk headers as `git diff` would produce them.
+use std::{io, ops::Range};
+
+use imara_diff::{
+    intern::{InternedInput, Interner, Token},
+    Algorithm, Sink,
+};
+
+/// Defines the size of the context printed before and after each change.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, Ord, PartialOrd)]
+pub struct ContextSize {
+    /// Defines the size of the context printed before and after each change, like `git diff -U<n>`.
+    pub symmetrical: u32,
+}
+
+impl Default for ContextSize {
+    fn default() -> Self {
+        ContextSize::symmetrical(3)
+    }
+}
+
+/// Instantiation
+impl ContextSize {
+    /// Create a symmetrical context with `n` lines before and after a changed hunk.
+    pub fn symmetrical(n: u32) -> Self {
+        ContextSize { symmetrical: n }
+    }
+}
+
+/// A [`Sink`] that writes hunks of changed lines in the unified diff format to `out`.
+///
+/// It expects lines to contain their line terminator, as obtained with [`ByteLines<'_, true>`](imara_diff::sources::ByteLines)
+/// or [`Resource::intern_source()`](super::platform::Resource::intern_source()).
+/// Lines without terminator can only occur at the end of a file and are followed by `\ No newline at end of file`.
+///
+/// Hunk headers and lines are written once a hunk is complete, and errors are deferred until [`finish()`](Sink::finish()).
+pub struct UnifiedDiff<'a, W> {
+    before: &'a [Token],
+    after: &'a [Token],
+    interner: &'a Interner<&'a [u8]>,
+
+    /// The position in `before` up to which all lines were processed.
+    pos: u32,
+    before_hunk_start: u32,
+    after_hunk_start: u32,
+    before_hunk_len: u32,
+    after_hunk_len: u32,
+    /// If `true`, the current hunk received at least one change.
+    has_hunk: bool,
+
+    buffer: Vec<u8>,
+    out: W,
+    err: Option<io::Error>,
+    context_size: ContextSize,
+}
+
+impl<'a, W> UnifiedDiff<'a, W>
+where
+    W: io::Write,
+{
+    /// Create a new instance to write hunks of changes between the tokens in `input` to `out`,
+    /// showing `context_size` lines of unchanged context around each change.
+    pub fn new(input: &'a InternedInput<&'a [u8]>, out: W, context_size: ContextSize) -> Self {
+        UnifiedDiff {
+            before: &input.before,
+            after: &input.after,
+            interner: &input.interner,
+            pos: 0,
+            before_hunk_start: 0,
+            after_hunk_start: 0,
+            before_hunk_len: 0,
+            after_hunk_len: 0,
+            has_hunk: false,
+            buffer: Vec::new(),
+            out,
+            err: None,
+            context_size,
+        }
+    }
+
+    fn push_lines(&mut self, prefix: u8, tokens: Range<u32>, side: &'a [Token]) {
+        for &token in &side[tokens.start as usize..tokens.end as usize] {
+            let line = self.interner[token];
+            self.buffer.push(prefix);
+            self.buffer.extend_from_slice(line);
+            if !line.ends_with(b"\n") {
+                self.buffer.extend_from_slice(b"\n\\ No newline at end of file\n");
+            }
+        }
+    }
+
+    /// Print unchanged context lines up to `print_to` and continue processing lines at `move_to`.
+    fn update_pos(&mut self, print_to: u32, move_to: u32) {
+        let before = self.before;
+        self.push_lines(b' ', self.pos..print_to, before);
+        let len = print_to - self.pos;
+        self.before_hunk_len += len;
+        self.after_hunk_len += len;
+        self.pos = move_to;
+    }
+
+    fn flush(&mut self) {
+        if !self.has_hunk {
+            return;
+        }
+        let end = (self.pos + self.context_size.symmetrical).min(self.before.len() as u32);
+        self.update_pos(end, end);
+
+        if self.err.is_none() {
+            let res = write_hunk_header(
+                &mut self.out,
+                self.before_hunk_start,
+                self.before_hunk_len,
+                self.after_hunk_start,
+                self.after_hunk_len,
+            )
+            .and_then(|_| self.out.write_all(&self.buffer));
+            self.err = res.err();
+        }
+        self.buffer.clear();
+        self.before_hunk_len = 0;
+        self.after_hunk_len = 0;
+        self.has_hunk = false;
+    }
+}
+
+impl<'a, W> Sink for UnifiedDiff<'a, W>
+where
+    W: io::Write,
+{
+    type Out = io::Result<W>;
+
+    fn process_change(&mut self, before: Range<u32>, after: Range<u32>) {
+        let context_len = self.context_size.symmetrical;
+        if self.has_hunk && before.start - self.pos > 2 * context_len {
+            self.flush();
+        }
+        if !self.has_hunk {
+            let leading_context_len = before.start.min(context_len);
+            self.pos = before.start - leading_context_len;
+            self.before_hunk_start = self.pos;
+            self.after_hunk_start = after.start - leading_context_len;
+            self.has_hunk = true;
+        }
+        self.update_pos(before.start, before.end);
+        self.before_hunk_len += before.end - before.start;
+        self.after_hunk_len += after.end - after.start;
+
+        let (before_tokens, after_tokens) = (self.before, self.after);
+        self.push_lines(b'-', before, before_tokens);
+        self.push_lines(b'+', after, after_tokens);
+    }
+
+    fn finish(mut self) -> Self::Out {
+        self.flush();
+        match self.err {
+            Some(err) => Err(err),
+            None => Ok(self.out),
+        }
+    }
+}
+
+/// Write a hunk header like `@@ -1,3 +1,4 @@` for the given 0-based starts and lengths, just like `git` would.
+fn write_hunk_header(
+    out: &mut dyn io::Write,
+    before_start: u32,
+    before_len: u32,
+    after_start: u32,
+    after_len: u32,
+) -> io::Result<()> {
+    fn range(out: &mut dyn io::Write, start: u32, len: u32) -> io::Result<()> {
+        // Empty ranges refer to the line before them.
+        let start = if len == 0 { start } else { start + 1 };
+        if len == 1 {
+            write!(out, "{start}")
+        } else {
+            write!(out, "{start},{len}")
+        }
+    }
+    out.write_all(b"@@ -")?;
+    range(out, before_start, before_len)?;
+    out.write_all(b" +")?;
+    range(out, after_start, after_len)?;
+    out.write_all(b" @@\n")
+}
+
+/// Write the changes needed to turn `old` into `new` as hunks in the unified diff format to `out`,
+/// with `context_size` lines around each change, using the given diff `algorithm`.
+///
+/// If either `old` or `new` is considered binary, only `Binary files differ` is written, and only if they actually differ.
+/// Note that neither a `diff --git` header nor `---` and `+++` lines are written, as these require path information
+/// that is up to the caller.
+///
+/// The buffers are typically obtained from [`Platform::prepare_diff()`](super::Platform::prepare_diff()), and are
+/// expected to have been converted to their diffable form.
+pub fn write<W: io::Write>(
+    old: &[u8],
+    new: &[u8],
+    algorithm: Algorithm,
+    context_size: ContextSize,
+    mut out: W,
+) -> io::Result<W> {
+    if super::is_binary(old) || super::is_binary(new) {
+        if old != new {
+            out.write_all(b"Binary files differ\n")?;
+        }
+        return Ok(out);
+    }
+    let input = InternedInput::new(
+        imara_diff::sources::byte_lines_with_terminator(old),
+        imara_diff::sources::byte_lines_with_terminator(new),
+    );
+    imara_diff::diff(algorithm, &input, UnifiedDiff::new(&input, out, context_size))
+}
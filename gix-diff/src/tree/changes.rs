@@ -38,6 +38,9 @@ impl<'a> tree::Changes<'a> {
     ///   by the delegate implementation which should be as specific as possible. Rename tracking can be computed on top of the changes
     ///   received by the `delegate`.
     /// * cycle checking is not performed, but can be performed in the delegate which can return [`tree::visit::Action::Cancel`] to stop the traversal.
+    /// * submodules, i.e. entries of kind [`Commit`][gix_object::tree::EntryKind::Commit], are never traversed as the commits they
+    ///   point to aren't expected to be in `objects`. Thus changes to them are reported like changes to any other non-tree entry.
+    /// * the `delegate` may return [`tree::visit::Action::Skip`] when visiting a tree to prevent recursing into it.
     /// * [`std::mem::ManuallyDrop`] is used because `Peekable` is needed. When using it as wrapper around our no-drop iterators, all of the sudden
     ///   borrowcheck complains as Drop is present (even though it's not)
     ///
@@ -109,6 +112,34 @@ impl<'a> tree::Changes<'a> {
     }
 }
 
+impl tree::Changes<'_> {
+    /// Calculate the changes needed to turn the tree encoded in `lhs` into the one encoded in `rhs`, passing them to `delegate`.
+    ///
+    /// This is a convenience over [`needed_to_obtain()`][tree::Changes::needed_to_obtain()] which decodes both trees
+    /// internally, so `lhs` and `rhs` can be owned buffers, like a `Vec<u8>`, or borrowed ones alike.
+    /// An empty buffer is treated like an empty tree, which makes it possible to diff against nothing.
+    ///
+    /// `state`, `objects` and `delegate` are used just like in [`needed_to_obtain()`][tree::Changes::needed_to_obtain()].
+    pub fn between<R, StateMut>(
+        lhs: impl AsRef<[u8]>,
+        rhs: impl AsRef<[u8]>,
+        state: StateMut,
+        objects: impl gix_object::Find,
+        delegate: &mut R,
+    ) -> Result<(), Error>
+    where
+        R: tree::Visit,
+        StateMut: BorrowMut<tree::State>,
+    {
+        tree::Changes::from(gix_object::TreeRefIter::from_bytes(lhs.as_ref())).needed_to_obtain(
+            gix_object::TreeRefIter::from_bytes(rhs.as_ref()),
+            state,
+            objects,
+            delegate,
+        )
+    }
+}
+
 fn compare(a: &EntryRef<'_>, b: &EntryRef<'_>) -> std::cmp::Ordering {
     let common = a.filename.len().min(b.filename.len());
     a.filename[..common].cmp(&b.filename[..common]).then_with(|| {
@@ -124,16 +155,14 @@ fn delete_entry_schedule_recursion<R: tree::Visit>(
     delegate: &mut R,
 ) -> Result<(), Error> {
     delegate.push_path_component(entry.filename);
-    if delegate
-        .visit(Change::Deletion {
-            entry_mode: entry.mode,
-            oid: entry.oid.to_owned(),
-        })
-        .cancelled()
-    {
+    let action = delegate.visit(Change::Deletion {
+        entry_mode: entry.mode,
+        oid: entry.oid.to_owned(),
+    });
+    if action.cancelled() {
         return Err(Error::Cancelled);
     }
-    if entry.mode.is_tree() {
+    if entry.mode.is_tree() && !action.skipped() {
         delegate.pop_path_component();
         delegate.push_back_tracked_path_component(entry.filename);
         queue.push_back((Some(entry.oid.to_owned()), None));
@@ -147,16 +176,14 @@ fn add_entry_schedule_recursion<R: tree::Visit>(
     delegate: &mut R,
 ) -> Result<(), Error> {
     delegate.push_path_component(entry.filename);
-    if delegate
-        .visit(Change::Addition {
-            entry_mode: entry.mode,
-            oid: entry.oid.to_owned(),
-        })
-        .cancelled()
-    {
+    let action = delegate.visit(Change::Addition {
+        entry_mode: entry.mode,
+        oid: entry.oid.to_owned(),
+    });
+    if action.cancelled() {
         return Err(Error::Cancelled);
     }
-    if entry.mode.is_tree() {
+    if entry.mode.is_tree() && !action.skipped() {
         delegate.pop_path_component();
         delegate.push_back_tracked_path_component(entry.filename);
         queue.push_back((None, Some(entry.oid.to_owned())))
@@ -251,23 +278,28 @@ fn handle_lhs_and_rhs_with_equal_filenames<R: tree::Visit>(
 ) -> Result<(), Error> {
     match (lhs.mode.is_tree(), rhs.mode.is_tree()) {
         (true, true) => {
-            delegate.push_back_tracked_path_component(lhs.filename);
-            if lhs.oid != rhs.oid
-                && delegate
-                    .visit(Change::Modification {
-                        previous_entry_mode: lhs.mode,
-                        previous_oid: lhs.oid.to_owned(),
-                        entry_mode: rhs.mode,
-                        oid: rhs.oid.to_owned(),
-                    })
-                    .cancelled()
-            {
+            delegate.push_path_component(lhs.filename);
+            let action = if lhs.oid != rhs.oid {
+                delegate.visit(Change::Modification {
+                    previous_entry_mode: lhs.mode,
+                    previous_oid: lhs.oid.to_owned(),
+                    entry_mode: rhs.mode,
+                    oid: rhs.oid.to_owned(),
+                })
+            } else {
+                tree::visit::Action::Continue
+            };
+            if action.cancelled() {
                 return Err(Error::Cancelled);
             }
-            queue.push_back((Some(lhs.oid.to_owned()), Some(rhs.oid.to_owned())));
+            if !action.skipped() {
+                delegate.pop_path_component();
+                delegate.push_back_tracked_path_component(lhs.filename);
+                queue.push_back((Some(lhs.oid.to_owned()), Some(rhs.oid.to_owned())));
+            }
         }
         (_, true) => {
-            delegate.push_back_tracked_path_component(lhs.filename);
+            delegate.push_path_component(lhs.filename);
             if delegate
                 .visit(Change::Deletion {
                     entry_mode: lhs.mode,
@@ -277,26 +309,26 @@ fn handle_lhs_and_rhs_with_equal_filenames<R: tree::Visit>(
             {
                 return Err(Error::Cancelled);
             };
-            if delegate
-                .visit(Change::Addition {
-                    entry_mode: rhs.mode,
-                    oid: rhs.oid.to_owned(),
-                })
-                .cancelled()
-            {
+            let action = delegate.visit(Change::Addition {
+                entry_mode: rhs.mode,
+                oid: rhs.oid.to_owned(),
+            });
+            if action.cancelled() {
                 return Err(Error::Cancelled);
             };
-            queue.push_back((None, Some(rhs.oid.to_owned())));
+            if !action.skipped() {
+                delegate.pop_path_component();
+                delegate.push_back_tracked_path_component(lhs.filename);
+                queue.push_back((None, Some(rhs.oid.to_owned())));
+            }
         }
         (true, _) => {
-            delegate.push_back_tracked_path_component(lhs.filename);
-            if delegate
-                .visit(Change::Deletion {
-                    entry_mode: lhs.mode,
-                    oid: lhs.oid.to_owned(),
-                })
-                .cancelled()
-            {
+            delegate.push_path_component(lhs.filename);
+            let action = delegate.visit(Change::Deletion {
+                entry_mode: lhs.mode,
+                oid: lhs.oid.to_owned(),
+            });
+            if action.cancelled() {
                 return Err(Error::Cancelled);
             }
             if delegate
@@ -308,12 +340,16 @@ fn handle_lhs_and_rhs_with_equal_filenames<R: tree::Visit>(
             {
                 return Err(Error::Cancelled);
             };
-            queue.push_back((Some(lhs.oid.to_owned()), None));
+            if !action.skipped() {
+                delegate.pop_path_component();
+                delegate.push_back_tracked_path_component(lhs.filename);
+                queue.push_back((Some(lhs.oid.to_owned()), None));
+            }
         }
         (false, false) => {
             delegate.push_path_component(lhs.filename);
-            debug_assert!(lhs.mode.is_no_tree() && lhs.mode.is_no_tree());
-            if lhs.oid != rhs.oid
+            debug_assert!(lhs.mode.is_no_tree() && rhs.mode.is_no_tree());
+            if (lhs.oid != rhs.oid || lhs.mode != rhs.mode)
                 && delegate
                     .visit(Change::Modification {
                         previous_entry_mode: lhs.mode,
@@ -53,3 +53,19 @@ pub struct Recorder {
 
 /// Useful for use as delegate implementing [`Visit`] to keep track of all seen changes. Useful for debugging or printing primarily.
 pub mod recorder;
+
+/// A [Visit] implementation to forward only changes at or below a path prefix to a delegate, while not recursing
+/// into trees that can't contain any matching path.
+///
+/// Changes to the trees leading up to the prefix aren't forwarded, but all calls altering the path are, so the delegate
+/// can keep track of paths as usual.
+#[derive(Clone, Debug)]
+pub struct PrefixFilter<D> {
+    path_deque: VecDeque<BString>,
+    path: BString,
+    prefix: BString,
+    /// The delegate to receive all changes that match the prefix.
+    pub delegate: D,
+}
+
+mod prefix_filter;
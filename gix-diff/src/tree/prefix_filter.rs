@@ -0,0 +1,96 @@
+use gix_object::bstr::{BStr, BString, ByteSlice, ByteVec};
+
+use crate::tree::{visit, PrefixFilter};
+
+/// Instantiation
+impl<D> PrefixFilter<D> {
+    /// Create a new instance which forwards changes at or below `prefix` to `delegate`.
+    ///
+    /// `prefix` is a slash-separated path relative to the root of the tree, like `src/tree`, which matches itself as well as
+    /// everything inside of it if it is a tree. Trailing slashes are ignored, and an empty prefix matches everything.
+    pub fn new(prefix: impl Into<BString>, delegate: D) -> Self {
+        let mut prefix = prefix.into();
+        let len = prefix.trim_end_with(|c| c == '/').len();
+        prefix.truncate(len);
+        PrefixFilter {
+            path_deque: Default::default(),
+            path: Default::default(),
+            prefix,
+            delegate,
+        }
+    }
+
+    /// Return the delegate that received all matching changes.
+    pub fn into_inner(self) -> D {
+        self.delegate
+    }
+}
+
+/// Access
+impl<D> PrefixFilter<D> {
+    /// Return the prefix that paths have to match.
+    pub fn prefix(&self) -> &BStr {
+        self.prefix.as_ref()
+    }
+}
+
+impl<D> PrefixFilter<D> {
+    fn pop_element(&mut self) {
+        if let Some(pos) = self.path.rfind_byte(b'/') {
+            self.path.resize(pos, 0);
+        } else {
+            self.path.clear();
+        }
+    }
+
+    fn push_element(&mut self, name: &BStr) {
+        if !self.path.is_empty() {
+            self.path.push(b'/');
+        }
+        self.path.push_str(name);
+    }
+
+    /// Return `true` if the current path is the prefix or is contained in it.
+    fn path_is_at_or_below_prefix(&self) -> bool {
+        self.prefix.is_empty()
+            || (self.path.starts_with(&self.prefix) && self.path.get(self.prefix.len()).map_or(true, |b| *b == b'/'))
+    }
+
+    /// Return `true` if the current path is a leading directory of the prefix.
+    fn path_is_above_prefix(&self) -> bool {
+        self.prefix.starts_with(&self.path) && self.prefix.get(self.path.len()) == Some(&b'/')
+    }
+}
+
+impl<D: visit::Visit> visit::Visit for PrefixFilter<D> {
+    fn pop_front_tracked_path_and_set_current(&mut self) {
+        self.path = self.path_deque.pop_front().expect("every parent is set only once");
+        self.delegate.pop_front_tracked_path_and_set_current();
+    }
+
+    fn push_back_tracked_path_component(&mut self, component: &BStr) {
+        self.push_element(component);
+        self.path_deque.push_back(self.path.clone());
+        self.delegate.push_back_tracked_path_component(component);
+    }
+
+    fn push_path_component(&mut self, component: &BStr) {
+        self.push_element(component);
+        self.delegate.push_path_component(component);
+    }
+
+    fn pop_path_component(&mut self) {
+        self.pop_element();
+        self.delegate.pop_path_component();
+    }
+
+    fn visit(&mut self, change: visit::Change) -> visit::Action {
+        if self.path_is_at_or_below_prefix() {
+            self.delegate.visit(change)
+        } else if change.entry_mode().is_tree() && self.path_is_above_prefix() {
+            visit::Action::Continue
+        } else {
+            visit::Action::Skip
+        }
+    }
+}
@@ -43,6 +43,35 @@ pub enum Change {
     },
 }
 
+impl Change {
+    /// Return `true` if this is a [`Modification`][Change::Modification] that changed only the mode of an entry,
+    /// like making a file executable, but not its content.
+    pub fn is_mode_only(&self) -> bool {
+        match self {
+            Change::Modification {
+                previous_entry_mode,
+                previous_oid,
+                entry_mode,
+                oid,
+                ..
+            } => visit::is_mode_only(previous_oid, *previous_entry_mode, oid, *entry_mode),
+            Change::Addition { .. } | Change::Deletion { .. } => false,
+        }
+    }
+    /// Return `true` if this is a [`Modification`][Change::Modification] that changed the type of an entry,
+    /// like turning a file into a symbolic link or a submodule, which `git` reports as type change.
+    pub fn is_type_change(&self) -> bool {
+        match self {
+            Change::Modification {
+                previous_entry_mode,
+                entry_mode,
+                ..
+            } => visit::is_type_change(*previous_entry_mode, *entry_mode),
+            Change::Addition { .. } | Change::Deletion { .. } => false,
+        }
+    }
+}
+
 impl Default for Recorder {
     fn default() -> Self {
         Recorder {
@@ -48,6 +48,34 @@ impl Change {
             | Change::Modification { entry_mode, .. } => *entry_mode,
         }
     }
+    /// Return `true` if this is a [`Modification`][Change::Modification] that changed only the mode of an entry,
+    /// like making a file executable, but not its content.
+    pub fn is_mode_only(&self) -> bool {
+        match self {
+            Change::Modification {
+                previous_entry_mode,
+                previous_oid,
+                entry_mode,
+                oid,
+            } => is_mode_only(previous_oid, *previous_entry_mode, oid, *entry_mode),
+            Change::Addition { .. } | Change::Deletion { .. } => false,
+        }
+    }
+    /// Return `true` if this is a [`Modification`][Change::Modification] that changed the type of an entry,
+    /// like turning a file into a symbolic link or a submodule, which `git` reports as type change.
+    ///
+    /// Note that changes between trees and non-trees are always represented as [deletion][Change::Deletion]
+    /// followed by an [addition][Change::Addition] or vice versa, and never as type change.
+    pub fn is_type_change(&self) -> bool {
+        match self {
+            Change::Modification {
+                previous_entry_mode,
+                entry_mode,
+                ..
+            } => is_type_change(*previous_entry_mode, *entry_mode),
+            Change::Addition { .. } | Change::Deletion { .. } => false,
+        }
+    }
     /// Return the current object id and tree entry mode of a change.
     pub fn oid_and_entry_mode(&self) -> (&gix_hash::oid, EntryMode) {
         match self {
@@ -58,6 +86,22 @@ impl Change {
     }
 }
 
+/// Return `true` if only the mode changed between the `previous` and `current` version of an entry, but not its object id.
+pub(crate) fn is_mode_only(previous_oid: &ObjectId, previous: EntryMode, oid: &ObjectId, current: EntryMode) -> bool {
+    previous_oid == oid && previous != current
+}
+
+/// Return `true` if `previous` and `current` are of different types, with executable and non-executable blobs being of the same type.
+pub(crate) fn is_type_change(previous: EntryMode, current: EntryMode) -> bool {
+    fn normalize(mode: EntryMode) -> tree::EntryKind {
+        match mode.kind() {
+            tree::EntryKind::BlobExecutable => tree::EntryKind::Blob,
+            kind => kind,
+        }
+    }
+    normalize(previous) != normalize(current)
+}
+
 /// What to do after a [Change] was [recorded][Visit::visit()].
 #[derive(Default, Clone, Copy, PartialOrd, PartialEq, Ord, Eq, Hash)]
 pub enum Action {
@@ -66,6 +110,10 @@ pub enum Action {
     Continue,
     /// Stop the traversal of changes, making this the last call to [visit(…)][Visit::visit()].
     Cancel,
+    /// Don't dive into the entry, skipping its children effectively.
+    ///
+    /// This is only meaningful for changes to trees, and acts like [`Continue`][Action::Continue] otherwise.
+    Skip,
 }
 
 impl Action {
@@ -73,6 +121,11 @@ impl Action {
     pub fn cancelled(&self) -> bool {
         matches!(self, Action::Cancel)
     }
+
+    /// Returns true if this action means to not recurse into the tree that was just visited.
+    pub fn skipped(&self) -> bool {
+        matches!(self, Action::Skip)
+    }
 }
 
 /// A trait to allow responding to a traversal designed to figure out the [changes][Change]
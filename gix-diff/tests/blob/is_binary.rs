@@ -0,0 +1,46 @@
+use gix_diff::blob::{is_binary, is_binary_stream, BINARY_DETECTION_LIMIT};
+
+#[test]
+fn nul_bytes_within_the_limit_make_data_binary() -> crate::Result {
+    for (data, expected) in [
+        (b"".to_vec(), false),
+        (b"text\nwith lines\n".to_vec(), false),
+        (b"\0".to_vec(), true),
+        (b"text\0".to_vec(), true),
+        ("non-ascii is text: \u{1F600}".as_bytes().to_vec(), false),
+    ] {
+        assert_eq!(is_binary(&data), expected, "{data:?}");
+        assert_eq!(is_binary_stream(data.as_slice())?, expected, "{data:?}");
+    }
+    Ok(())
+}
+
+#[test]
+fn nul_bytes_beyond_the_limit_are_ignored() -> crate::Result {
+    let mut data = vec![b'a'; BINARY_DETECTION_LIMIT];
+    data.push(0);
+    assert!(!is_binary(&data));
+    assert!(!is_binary_stream(data.as_slice())?);
+
+    data[BINARY_DETECTION_LIMIT - 1] = 0;
+    assert!(is_binary(&data), "the last byte within the limit counts");
+    assert!(is_binary_stream(data.as_slice())?);
+    Ok(())
+}
+
+#[test]
+fn streaming_reads_no_more_than_needed() -> crate::Result {
+    let data = [b"a\0".as_slice(), &[b'b'; BINARY_DETECTION_LIMIT * 2]].concat();
+    let mut read = data.as_slice();
+    assert!(is_binary_stream(&mut read)?);
+    assert!(
+        read.len() >= data.len() - BINARY_DETECTION_LIMIT,
+        "reading stops early once a NUL byte was found"
+    );
+
+    let data = vec![b'a'; BINARY_DETECTION_LIMIT * 2];
+    let mut read = data.as_slice();
+    assert!(!is_binary_stream(&mut read)?);
+    assert_eq!(read.len(), BINARY_DETECTION_LIMIT, "only the first bytes are read");
+    Ok(())
+}
@@ -1,2 +1,4 @@
 pub(crate) mod pipeline;
+mod is_binary;
 mod platform;
+mod unified_diff;
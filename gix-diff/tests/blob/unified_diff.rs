@@ -0,0 +1,138 @@
+use gix_diff::blob::{
+    unified_diff::{self, ContextSize},
+    Algorithm,
+};
+
+const BEFORE: &str = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n13\n14\n15\n";
+const AFTER: &str = "1\n2\nX\n4\n5\n6\n7\n8\n9\n10\n11\n12\n13\n14\n15\nnew";
+
+#[test]
+fn separate_hunks_with_default_context() -> crate::Result {
+    assert_eq!(
+        diff(BEFORE, AFTER, ContextSize::default())?,
+        "@@ -1,6 +1,6 @@
+ 1
+ 2
+-3
++X
+ 4
+ 5
+ 6
+@@ -13,3 +13,4 @@
+ 13
+ 14
+ 15
++new
+\\ No newline at end of file
+"
+    );
+    Ok(())
+}
+
+#[test]
+fn single_line_hunks_omit_their_length() -> crate::Result {
+    assert_eq!(
+        diff(BEFORE, AFTER, ContextSize::symmetrical(1))?,
+        "@@ -2,3 +2,3 @@
+ 2
+-3
++X
+ 4
+@@ -15 +15,2 @@
+ 15
++new
+\\ No newline at end of file
+"
+    );
+    Ok(())
+}
+
+#[test]
+fn hunks_whose_context_touches_are_merged() -> crate::Result {
+    assert_eq!(
+        diff(BEFORE, AFTER, ContextSize::symmetrical(6))?,
+        "@@ -1,15 +1,16 @@
+ 1
+ 2
+-3
++X
+ 4
+ 5
+ 6
+ 7
+ 8
+ 9
+ 10
+ 11
+ 12
+ 13
+ 14
+ 15
++new
+\\ No newline at end of file
+"
+    );
+    Ok(())
+}
+
+#[test]
+fn additions_to_empty_file_start_at_line_zero() -> crate::Result {
+    assert_eq!(
+        diff("", "a\nb\n", ContextSize::default())?,
+        "@@ -0,0 +1,2 @@
++a
++b
+"
+    );
+    assert_eq!(
+        diff("a\nb\n", "", ContextSize::default())?,
+        "@@ -1,2 +0,0 @@
+-a
+-b
+"
+    );
+    Ok(())
+}
+
+#[test]
+fn missing_newline_is_marked_when_added() -> crate::Result {
+    assert_eq!(
+        diff("a\nb", "a\nb\n", ContextSize::default())?,
+        "@@ -1,2 +1,2 @@
+ a
+-b
+\\ No newline at end of file
++b
+"
+    );
+    Ok(())
+}
+
+#[test]
+fn identical_inputs_produce_no_output() -> crate::Result {
+    assert_eq!(diff(BEFORE, BEFORE, ContextSize::default())?, "");
+    Ok(())
+}
+
+#[test]
+fn binary_files_are_not_diffed() -> crate::Result {
+    assert_eq!(diff("a\0b", "a\0c", ContextSize::default())?, "Binary files differ\n");
+    assert_eq!(diff("text", "a\0c", ContextSize::default())?, "Binary files differ\n");
+    assert_eq!(
+        diff("a\0b", "a\0b", ContextSize::default())?,
+        "",
+        "nothing is printed if there is no difference"
+    );
+    Ok(())
+}
+
+fn diff(old: &str, new: &str, context_size: ContextSize) -> crate::Result<String> {
+    let out = unified_diff::write(
+        old.as_bytes(),
+        new.as_bytes(),
+        Algorithm::Myers,
+        context_size,
+        Vec::new(),
+    )?;
+    Ok(String::from_utf8(out)?)
+}
@@ -102,3 +102,10 @@ git commit -am "clear slate"
 git mv git-sec gix-sec && git commit -m "interesting rename 1"
 
 git mv gix-sec git-sec && git commit -m "interesting rename 2"
+
+chmod +x git-sequencer && git commit -qam "git-sequencer mode changed to executable"
+
+prefix=$(git rev-parse --show-prefix)
+git update-index --add --cacheinfo 160000,1111111111111111111111111111111111111111,${prefix}submodule && git commit -qm "add submodule"
+git update-index --cacheinfo 160000,2222222222222222222222222222222222222222,${prefix}submodule && git commit -qm "submodule modified"
+git update-index --cacheinfo 160000,1111111111111111111111111111111111111111,${prefix}h && git commit -qm "h changed into submodule"
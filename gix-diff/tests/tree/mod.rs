@@ -67,6 +67,28 @@ mod changes {
             Ok(recorder.records)
         }
 
+        fn diff_commits_with_prefix(
+            db: &gix_odb::Handle,
+            lhs: impl Into<Option<ObjectId>>,
+            rhs: &oid,
+            prefix: &str,
+        ) -> crate::Result<Changes> {
+            let mut buf = Vec::new();
+            let lhs_tree = lhs
+                .into()
+                .and_then(|lhs| locate_tree_by_commit(db, &lhs, &mut buf).ok());
+            let mut buf2 = Vec::new();
+            let rhs_tree = locate_tree_by_commit(db, rhs, &mut buf2)?;
+            let mut filter = gix_diff::tree::PrefixFilter::new(prefix, gix_diff::tree::Recorder::default());
+            gix_diff::tree::Changes::from(lhs_tree).needed_to_obtain(
+                rhs_tree,
+                gix_diff::tree::State::default(),
+                db,
+                &mut filter,
+            )?;
+            Ok(filter.into_inner().records)
+        }
+
         fn diff_with_previous_commit_from(db: &gix_odb::Handle, commit_id: &oid) -> crate::Result<Changes> {
             let mut buf = Vec::new();
             let (main_tree_id, parent_commit_id) = {
@@ -110,6 +132,16 @@ mod changes {
             Ok(recorder.records)
         }
 
+        fn locate_parent(db: &gix_odb::Handle, commit: &oid) -> Option<ObjectId> {
+            let mut buf = Vec::new();
+            db.try_find(commit, &mut buf)
+                .ok()
+                .flatten()
+                .and_then(|(c, _l)| c.decode().ok())
+                .and_then(gix_object::ObjectRef::into_commit)
+                .and_then(|c| c.parents().next())
+        }
+
         fn head_of(db: &gix_odb::Handle) -> ObjectId {
             ObjectId::from_hex(
                 std::fs::read(
@@ -638,6 +670,186 @@ mod changes {
             Ok(())
         }
 
+        #[test]
+        fn prefix_filter() -> crate::Result {
+            let db = db(["a"].iter().copied())?;
+            let all_commits = all_commits(&db);
+            let commit = all_commits["add g/a"];
+
+            let expected = vec![
+                Addition {
+                    entry_mode: EntryKind::Tree.into(),
+                    oid: hex_to_id("496d6428b9cf92981dc9495211e6e1120fb6f2ba"),
+                    path: "a/g".into(),
+                },
+                Addition {
+                    entry_mode: EntryKind::Blob.into(),
+                    oid: hex_to_id("e69de29bb2d1d6434b8b29ae775ad8c2e48c5391"),
+                    path: "a/g/a".into(),
+                },
+            ];
+            assert_eq!(
+                diff_commits_with_prefix(&db, None::<ObjectId>, &commit, "a/g")?,
+                expected,
+                "only the prefix itself and everything below it is forwarded, but not its leading directories"
+            );
+            assert_eq!(
+                diff_commits_with_prefix(&db, None::<ObjectId>, &commit, "a/g/")?,
+                expected,
+                "trailing slashes are ignored"
+            );
+            assert_eq!(
+                diff_commits_with_prefix(&db, None::<ObjectId>, &commit, "a/g/a")?,
+                &expected[1..],
+                "prefixes may match blobs exactly"
+            );
+            assert_eq!(
+                diff_commits_with_prefix(&db, None::<ObjectId>, &commit, "a/")?.len(),
+                8,
+                "everything is below the prefix"
+            );
+            assert_eq!(
+                diff_commits_with_prefix(&db, None::<ObjectId>, &commit, "")?,
+                diff_commits(&db, None::<ObjectId>, &commit, Some(Location::Path))?,
+                "an empty prefix matches everything"
+            );
+            assert_eq!(
+                diff_commits_with_prefix(&db, None::<ObjectId>, &commit, "a/g/a/b")?,
+                vec![],
+                "blobs can't contain the prefix"
+            );
+            assert_eq!(
+                diff_commits_with_prefix(&db, None::<ObjectId>, &commit, "a/")?,
+                diff_commits_with_prefix(&db, None::<ObjectId>, &commit, "a")?,
+            );
+            assert_eq!(
+                diff_commits_with_prefix(&db, None::<ObjectId>, &commit, "a/g/aa")?,
+                vec![],
+                "prefixes match entire path components only"
+            );
+            assert_eq!(
+                diff_commits_with_prefix(&db, None::<ObjectId>, &commit, "b")?,
+                vec![],
+                "non-matching trees are skipped"
+            );
+
+            for (_, commit) in all_commits {
+                let mut expected = diff_with_previous_commit_from(&db, &commit)?;
+                expected.retain(|change| {
+                    let path = match change {
+                        Addition { path, .. } | Deletion { path, .. } | Modification { path, .. } => path,
+                    };
+                    path == "a/f" || path.starts_with(b"a/f/")
+                });
+                let previous = locate_parent(&db, &commit);
+                assert_eq!(
+                    diff_commits_with_prefix(&db, previous, &commit, "a/f")?,
+                    expected,
+                    "path tracking stays intact when skipping trees"
+                );
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn mode_only_modification() -> crate::Result {
+            let db = db(None)?;
+            let all_commits = all_commits(&db);
+
+            let changes =
+                diff_with_previous_commit_from(&db, &all_commits["git-sequencer mode changed to executable"])?;
+            assert_eq!(
+                changes,
+                vec![Modification {
+                    previous_entry_mode: EntryKind::Blob.into(),
+                    previous_oid: hex_to_id("e69de29bb2d1d6434b8b29ae775ad8c2e48c5391"),
+                    entry_mode: EntryKind::BlobExecutable.into(),
+                    oid: hex_to_id("e69de29bb2d1d6434b8b29ae775ad8c2e48c5391"),
+                    path: "git-sequencer".into()
+                }],
+                ":100644 100755 e69de29bb2d1d6434b8b29ae775ad8c2e48c5391 e69de29bb2d1d6434b8b29ae775ad8c2e48c5391 M	git-sequencer"
+            );
+            assert!(changes[0].is_mode_only());
+
+            let changes = diff_with_previous_commit_from(&db, &all_commits["f modified"])?;
+            assert!(!changes[0].is_mode_only(), "content changes aren't mode-only changes");
+            Ok(())
+        }
+
+        #[test]
+        #[cfg(not(windows))]
+        fn type_change() -> crate::Result {
+            let db = db(None)?;
+            let all_commits = all_commits(&db);
+
+            let changes = diff_with_previous_commit_from(&db, &all_commits["f/f mode changed to link"])?;
+            assert_eq!(changes.len(), 2);
+            assert!(
+                !changes[0].is_type_change(),
+                "trees that change content don't change type"
+            );
+            assert!(changes[1].is_type_change(), "a file turned into a symlink");
+            assert!(!changes[1].is_mode_only());
+
+            let changes =
+                diff_with_previous_commit_from(&db, &all_commits["git-sequencer mode changed to executable"])?;
+            assert!(
+                !changes[0].is_type_change(),
+                "executable and non-executable files are of the same type"
+            );
+
+            let changes = diff_with_previous_commit_from(&db, &all_commits["f mode modified to dir f/"])?;
+            assert!(
+                changes.iter().all(|change| !change.is_type_change()),
+                "changes between trees and non-trees are deletions and additions"
+            );
+            Ok(())
+        }
+
+        #[test]
+        fn submodules() -> crate::Result {
+            let db = db(None)?;
+            let all_commits = all_commits(&db);
+
+            assert_eq!(
+                diff_with_previous_commit_from(&db, &all_commits["add submodule"])?,
+                vec![Addition {
+                    entry_mode: EntryKind::Commit.into(),
+                    oid: hex_to_id("1111111111111111111111111111111111111111"),
+                    path: "submodule".into()
+                }],
+                "the commit of the submodule isn't present and doesn't have to be"
+            );
+            let changes = diff_with_previous_commit_from(&db, &all_commits["submodule modified"])?;
+            assert_eq!(
+                changes,
+                vec![Modification {
+                    previous_entry_mode: EntryKind::Commit.into(),
+                    previous_oid: hex_to_id("1111111111111111111111111111111111111111"),
+                    entry_mode: EntryKind::Commit.into(),
+                    oid: hex_to_id("2222222222222222222222222222222222222222"),
+                    path: "submodule".into()
+                }],
+                ":160000 160000 1111111111111111111111111111111111111111 2222222222222222222222222222222222222222 M	submodule"
+            );
+            assert!(!changes[0].is_type_change());
+
+            let changes = diff_with_previous_commit_from(&db, &all_commits["h changed into submodule"])?;
+            assert_eq!(
+                changes,
+                vec![Modification {
+                    previous_entry_mode: EntryKind::Blob.into(),
+                    previous_oid: hex_to_id("e69de29bb2d1d6434b8b29ae775ad8c2e48c5391"),
+                    entry_mode: EntryKind::Commit.into(),
+                    oid: hex_to_id("1111111111111111111111111111111111111111"),
+                    path: "h".into()
+                }],
+                ":100644 160000 e69de29bb2d1d6434b8b29ae775ad8c2e48c5391 1111111111111111111111111111111111111111 T	h"
+            );
+            assert!(changes[0].is_type_change(), "a file turned into a submodule");
+            Ok(())
+        }
+
         #[test]
         fn interesting_rename() -> crate::Result {
             let db = db(None)?;
@@ -703,5 +915,44 @@ mod changes {
             );
             Ok(())
         }
+
+        #[test]
+        fn between_owned_buffers() -> crate::Result {
+            let db = db(None)?;
+            let all_commits = all_commits(&db);
+            let commit = &all_commits["interesting rename 1"];
+
+            let tree_data = |commit: &oid| -> crate::Result<Vec<u8>> {
+                use gix_object::FindExt;
+                let mut buf = Vec::new();
+                let tree_id = db.find_commit(commit, &mut buf)?.tree();
+                Ok(FindExt::find(&db, &tree_id, &mut buf)?.data.to_vec())
+            };
+            let lhs = tree_data(&locate_parent(&db, commit).expect("parent present"))?;
+            let rhs = tree_data(commit)?;
+
+            let mut recorder = gix_diff::tree::Recorder::default();
+            gix_diff::tree::Changes::between(
+                lhs,
+                rhs.as_slice(),
+                gix_diff::tree::State::default(),
+                &db,
+                &mut recorder,
+            )?;
+            assert_eq!(
+                recorder.records,
+                diff_with_previous_commit_from(&db, commit)?,
+                "owned and borrowed buffers are decoded just like iterators"
+            );
+
+            let mut recorder = gix_diff::tree::Recorder::default();
+            gix_diff::tree::Changes::between(Vec::new(), rhs, gix_diff::tree::State::default(), &db, &mut recorder)?;
+            assert!(
+                recorder.records.iter().all(|change| matches!(change, Addition { .. })),
+                "an empty buffer is an empty tree"
+            );
+            assert!(!recorder.records.is_empty());
+            Ok(())
+        }
     }
 }
@@ -1,4 +1,4 @@
-use flate2::Compress;
+use flate2::{Compress, Compression};
 
 const BUF_SIZE: usize = 4096 * 8;
 
@@ -7,6 +7,7 @@ const BUF_SIZE: usize = 4096 * 8;
 /// Be sure to call `flush()` when done to finalize the deflate stream.
 pub struct Write<W> {
     compressor: Compress,
+    level: Compression,
     inner: W,
     buf: [u8; BUF_SIZE],
 }
@@ -17,7 +18,8 @@ where
 {
     fn clone(&self) -> Self {
         Write {
-            compressor: impls::new_compress(),
+            compressor: impls::new_compress(self.level),
+            level: self.level,
             inner: self.inner.clone(),
             buf: self.buf,
         }
@@ -31,18 +33,27 @@ mod impls {
 
     use crate::zlib::stream::deflate;
 
-    pub(crate) fn new_compress() -> Compress {
-        Compress::new(Compression::fast(), true)
+    pub(crate) fn new_compress(level: Compression) -> Compress {
+        Compress::new(level, true)
     }
 
     impl<W> deflate::Write<W>
     where
         W: io::Write,
     {
-        /// Create a new instance writing compressed bytes to `inner`.
+        /// Create a new instance writing compressed bytes to `inner`, favoring speed over size
+        /// just like `git` does for loose objects by default.
         pub fn new(inner: W) -> deflate::Write<W> {
+            Self::new_with_level(inner, Compression::fast())
+        }
+
+        /// Create a new instance writing bytes compressed with the given compression `level` to `inner`.
+        ///
+        /// The level ranges from `0` (no compression) to `9` (best compression), similar to `core.compression` in `git`.
+        pub fn new_with_level(inner: W, level: Compression) -> deflate::Write<W> {
             deflate::Write {
-                compressor: new_compress(),
+                compressor: new_compress(level),
+                level,
                 inner,
                 buf: [0; deflate::BUF_SIZE],
             }
@@ -55,6 +66,11 @@ mod impls {
             self.compressor.reset();
         }
 
+        /// Return the compression level used by this instance.
+        pub fn level(&self) -> Compression {
+            self.level
+        }
+
         /// Consume `self` and return the inner writer.
         pub fn into_inner(self) -> W {
             self.inner
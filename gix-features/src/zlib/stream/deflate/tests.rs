@@ -98,4 +98,27 @@ mod deflate_stream {
 
         assert_deflate_buffer(w.inner, bytes)
     }
+
+    #[test]
+    fn compression_levels() -> Result<(), Box<dyn std::error::Error>> {
+        let bytes = b"hello world, hello world, hello world\n".repeat(1000);
+        let mut sizes = Vec::new();
+        for level in [0, 1, 9] {
+            let mut w = deflate::Write::new_with_level(Vec::new(), flate2::Compression::new(level));
+            assert_eq!(w.level().level(), level);
+            w.write_all(&bytes)?;
+            w.flush()?;
+            sizes.push(w.inner.len());
+            assert_deflate_buffer(w.inner, &bytes)?;
+        }
+        assert!(sizes[0] > bytes.len(), "level 0 stores data uncompressed");
+        assert!(sizes[1] < sizes[0], "fast compression still compresses");
+        assert!(sizes[2] <= sizes[1], "best compression yields the smallest output");
+        assert_eq!(
+            deflate::Write::new(Vec::<u8>::new()).level(),
+            flate2::Compression::fast(),
+            "by default, speed is favored like git does for loose objects"
+        );
+        Ok(())
+    }
 }
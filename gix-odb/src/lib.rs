@@ -137,6 +137,8 @@ pub struct Store {
     use_multi_pack_index: bool,
     /// The hash kind to use for some operations
     object_hash: gix_hash::Kind,
+    /// The zlib compression level to use when writing loose objects.
+    compression: gix_features::zlib::Compression,
 }
 
 /// Create a new cached handle to the object store with support for additional options.
@@ -19,6 +19,8 @@ pub struct Options {
     /// The current directory of the process at the time of instantiation.
     /// If unset, it will be retrieved using `std::env::current_dir()`.
     pub current_dir: Option<std::path::PathBuf>,
+    /// The zlib compression level to use when writing loose objects, see [`loose::Store::with_compression()`][crate::loose::Store::with_compression()].
+    pub compression: gix_features::zlib::Compression,
 }
 
 impl Default for Options {
@@ -28,6 +30,7 @@ impl Default for Options {
             object_hash: Default::default(),
             use_multi_pack_index: true,
             current_dir: None,
+            compression: gix_features::zlib::Compression::fast(),
         }
     }
 }
@@ -77,6 +80,7 @@ impl Store {
             object_hash,
             use_multi_pack_index,
             current_dir,
+            compression,
         }: Options,
     ) -> std::io::Result<Self> {
         let _span = gix_features::trace::detail!("gix_odb::Store::at()");
@@ -118,6 +122,7 @@ impl Store {
             index: ArcSwap::new(Arc::new(SlotMapIndex::default())),
             use_multi_pack_index,
             object_hash,
+            compression,
             num_handles_stable: Default::default(),
             num_handles_unstable: Default::default(),
             num_disk_state_consolidation: Default::default(),
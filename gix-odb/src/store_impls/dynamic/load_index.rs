@@ -220,7 +220,7 @@ impl super::Store {
             Arc::new(
                 db_paths
                     .iter()
-                    .map(|path| crate::loose::Store::at(path, self.object_hash))
+                    .map(|path| crate::loose::Store::at(path, self.object_hash).with_compression(self.compression))
                     .collect::<Vec<_>>(),
             )
         } else {
@@ -59,6 +59,10 @@ impl loose::Store {
     /// The [`Id`][gix_hash::ObjectId]s returned by the iterator can typically be used in the [`locate(…)`][loose::Store::try_find()] method.
     /// _Note_ that the result is not sorted or stable, thus ordering can change between runs.
     ///
+    /// Only the fan-out directories are scanned, and files in them that aren't named after an object, like temporary files,
+    /// are skipped. As only ids are produced, objects are only read and decompressed if they are looked up, which allows to
+    /// filter cheaply by id before calling [`try_header()`][loose::Store::try_header()] or [`try_find()`][loose::Store::try_find()].
+    ///
     /// # Notes
     ///
     /// [`loose::Iter`] is used instead of `impl Iterator<…>` to allow using this iterator in struct fields, as is currently
@@ -12,6 +12,8 @@ pub struct Store {
     pub(crate) path: PathBuf,
     /// The kind of hash we should assume during iteration and when writing new objects.
     pub(crate) object_hash: gix_hash::Kind,
+    /// The zlib compression level to use when writing new objects.
+    pub(crate) compression: gix_features::zlib::Compression,
 }
 
 /// Initialization
@@ -26,9 +28,20 @@ impl Store {
         Store {
             path: objects_directory.into(),
             object_hash,
+            compression: gix_features::zlib::Compression::fast(),
         }
     }
 
+    /// Use the given zlib compression `level` when writing new objects, ranging from `0` (none) to `9` (best).
+    ///
+    /// It defaults to `1`, favoring speed just like `git` does if neither `core.looseCompression` nor `core.compression` are set.
+    /// Loose stores created by the [dynamic store][crate::Store] receive their level from
+    /// [`init::Options::compression`][crate::store::init::Options::compression].
+    pub fn with_compression(mut self, level: gix_features::zlib::Compression) -> Self {
+        self.compression = level;
+        self
+    }
+
     /// Return the path to our `objects` directory.
     pub fn path(&self) -> &Path {
         &self.path
@@ -38,6 +51,11 @@ impl Store {
     pub fn object_hash(&self) -> gix_hash::Kind {
         self.object_hash
     }
+
+    /// Return the zlib compression level used when writing objects.
+    pub fn compression(&self) -> gix_features::zlib::Compression {
+        self.compression
+    }
 }
 
 fn hash_path(id: &gix_hash::oid, mut root: PathBuf) -> PathBuf {
@@ -24,6 +24,8 @@ pub enum Error {
         source: tempfile::PersistError,
         target: PathBuf,
     },
+    #[error("The object was declared to have {expected} bytes, but {actual} bytes were streamed")]
+    SizeMismatch { expected: u64, actual: u64 },
 }
 
 impl crate::traits::Write for Store {
@@ -67,6 +69,8 @@ impl crate::traits::Write for Store {
     /// Write the given stream in `from` to disk with at least one syscall.
     ///
     /// This will cost at least 4 IO operations.
+    ///
+    /// It's an error if `from` doesn't yield exactly `size` bytes, in which case no object is written.
     fn write_stream(
         &self,
         kind: gix_object::Kind,
@@ -81,13 +85,16 @@ impl crate::traits::Write for Store {
                 path: self.path.to_owned(),
             })?;
 
-        io::copy(&mut from, &mut to)
+        let actual = io::copy(&mut from, &mut to)
             .map_err(|err| Error::Io {
                 source: err,
                 message: "stream all data into tempfile in",
                 path: self.path.to_owned(),
             })
             .map_err(Box::new)?;
+        if actual != size {
+            return Err(Box::new(Error::SizeMismatch { expected: size, actual }));
+        }
         to.flush().map_err(Box::new)?;
         Ok(self.finalize_object(to)?)
     }
@@ -108,11 +115,14 @@ impl Store {
 impl Store {
     fn dest(&self) -> Result<hash::Write<CompressedTempfile>, Error> {
         Ok(hash::Write::new(
-            deflate::Write::new(NamedTempFile::new_in(&self.path).map_err(|err| Error::Io {
-                source: err,
-                message: "create named temp file in",
-                path: self.path.to_owned(),
-            })?),
+            deflate::Write::new_with_level(
+                NamedTempFile::new_in(&self.path).map_err(|err| Error::Io {
+                    source: err,
+                    message: "create named temp file in",
+                    path: self.path.to_owned(),
+                })?,
+                self.compression,
+            ),
             self.object_hash,
         ))
     }
@@ -221,6 +221,37 @@ fn write() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn write_with_compression_level() -> crate::Result {
+    let data = b"hello world, hello world, hello world\n".repeat(100);
+    let mut sizes = Vec::new();
+    for level in [0, 9] {
+        let dir = gix_testtools::tempfile::tempdir()?;
+        let handle = gix_odb::at_opts(
+            dir.path(),
+            None,
+            gix_odb::store::init::Options {
+                compression: gix_features::zlib::Compression::new(level),
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(handle.store_ref().compression().level(), level);
+
+        let id = handle.write_buf(gix_object::Kind::Blob, &data)?;
+        let loose = gix_odb::loose::Store::at(dir.path(), gix_hash::Kind::Sha1);
+        sizes.push(std::fs::metadata(loose.object_path(&id))?.len());
+    }
+    assert!(
+        sizes[0] > sizes[1],
+        "the level is passed on to the loose stores the dynamic store writes to"
+    );
+    assert_eq!(
+        gix_odb::store::init::Options::default().compression,
+        gix_features::zlib::Compression::fast()
+    );
+    Ok(())
+}
+
 #[test]
 fn alternate_dbs_query() -> crate::Result {
     let dir = gix_testtools::scripted_fixture_read_only_standalone("make_alternates_odb.sh")?;
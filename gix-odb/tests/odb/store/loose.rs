@@ -31,6 +31,35 @@ fn iter() {
     oids.sort();
     assert_eq!(oids, object_ids());
 }
+
+#[test]
+fn iter_skips_files_that_are_not_objects() -> crate::Result {
+    use gix_odb::Write;
+    let dir = gix_testtools::tempfile::tempdir()?;
+    let db = Store::at(dir.path(), gix_hash::Kind::Sha1);
+    let id = db.write_buf(gix_object::Kind::Blob, b"content")?;
+
+    let fan_out = dir.path().join(&id.to_hex().to_string()[..2]);
+    for (dir, name) in [
+        (fan_out.as_path(), "tmp_obj_abcdef"),
+        (fan_out.as_path(), "not-hex-but-of-the-right-length-foobar"),
+        (fan_out.as_path(), ".tmpXYZ"),
+        (dir.path(), ".tmp0123"),
+    ] {
+        std::fs::write(dir.join(name), b"")?;
+    }
+    let pack_dir = dir.path().join("pack");
+    std::fs::create_dir(&pack_dir)?;
+    std::fs::write(pack_dir.join(format!("pack-{}.pack", id.to_hex())), b"")?;
+
+    assert_eq!(
+        db.iter().collect::<Result<Vec<_>, _>>()?,
+        vec![id],
+        "temporary files, non-hex names and packs are ignored"
+    );
+    Ok(())
+}
+
 pub fn locate_oid(id: gix_hash::ObjectId, buf: &mut Vec<u8>) -> gix_object::Data<'_> {
     ldb().try_find(&id, buf).expect("read success").expect("id present")
 }
@@ -101,6 +130,58 @@ mod write {
         Ok(())
     }
 
+    #[test]
+    fn write_stream_fails_if_the_declared_size_does_not_match() -> crate::Result {
+        let dir = gix_testtools::tempfile::tempdir()?;
+        let db = loose::Store::at(dir.path(), gix_hash::Kind::Sha1);
+        for (declared_size, data) in [(3, b"four".as_slice()), (5, b"four".as_slice())] {
+            let err = db
+                .write_stream(gix_object::Kind::Blob, declared_size, &mut &*data)
+                .unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("The object was declared to have {declared_size} bytes, but 4 bytes were streamed")
+            );
+        }
+        assert_eq!(db.iter().count(), 0, "nothing was written");
+
+        let id = db.write_stream(gix_object::Kind::Blob, 4, &mut b"four".as_slice())?;
+        assert_eq!(id, db.write_buf(gix_object::Kind::Blob, b"four")?);
+        Ok(())
+    }
+
+    #[test]
+    fn compression_level_is_configurable() -> crate::Result {
+        let data = b"hello world, hello world, hello world\n".repeat(100);
+        let mut ids_and_sizes = Vec::new();
+        for level in [0, 9] {
+            let dir = gix_testtools::tempfile::tempdir()?;
+            let db = loose::Store::at(dir.path(), gix_hash::Kind::Sha1)
+                .with_compression(gix_features::zlib::Compression::new(level));
+            let id = db.write_buf(gix_object::Kind::Blob, &data)?;
+            let mut buf = Vec::new();
+            assert_eq!(
+                db.try_find(&id, &mut buf)?.expect("present").data,
+                data,
+                "objects can be read back at any level"
+            );
+            ids_and_sizes.push((id, std::fs::metadata(db.object_path(&id))?.len()));
+        }
+        assert_eq!(
+            ids_and_sizes[0].0, ids_and_sizes[1].0,
+            "the level doesn't affect the id"
+        );
+        assert!(
+            ids_and_sizes[0].1 > ids_and_sizes[1].1,
+            "no compression takes more space than the best compression"
+        );
+        assert_eq!(
+            loose::Store::at("irrelevant", gix_hash::Kind::Sha1).compression(),
+            gix_features::zlib::Compression::fast()
+        );
+        Ok(())
+    }
+
     #[test]
     fn collisions_do_not_cause_failure() -> crate::Result {
         let dir = gix_testtools::tempfile::tempdir()?;
@@ -31,6 +31,19 @@ impl<'a> MatchGroup<'a> {
     /// *Note that this method is correct only for specs*, even though it also *works for push-specs*.
     ///
     /// Note that negative matches are not part of the return value, so they are not observable but will be used to remove mappings.
+    ///
+    /// ### Deduplication and Ordering
+    ///
+    /// Each distinct pair of source and destination appears only once, even if multiple overlapping specs produce it,
+    /// in which case the mapping of the first spec is retained.
+    /// Mappings are ordered by spec first, and by item second, just like `git` does it. Mappings of specs that name an object
+    /// instead of a reference come first as they don't depend on `items`.
+    ///
+    /// ### Object Ids
+    ///
+    /// Just like `git`, specs naming an object by id always map that very object without consulting `items`, no matter
+    /// if an item's `target` is the object or, in case of annotated tags, its peeled `object`. Servers are expected to reject
+    /// requests for objects they don't have.
     // TODO: figure out how to deal with push-specs, probably when push is being implemented.
     pub fn match_remotes<'item>(self, mut items: impl Iterator<Item = Item<'item>> + Clone) -> Outcome<'a, 'item> {
         let mut out = Vec::new();
@@ -67,10 +67,13 @@ impl<'a> Needle<'a> {
                     Match::None
                 }
             }
-            Needle::PartialName(name) => crate::spec::expand_partial_name(name, |expanded| {
-                (expanded == item.full_ref_name).then_some(Match::Normal)
-            })
-            .unwrap_or(Match::None),
+            Needle::PartialName(name) => {
+                if crate::spec::is_expansion_of_partial_name(name, item.full_ref_name) {
+                    Match::Normal
+                } else {
+                    Match::None
+                }
+            }
             Needle::Glob { name, asterisk_pos } => {
                 match item.full_ref_name.get(..*asterisk_pos) {
                     Some(full_name_portion) if full_name_portion != name[..*asterisk_pos] => {
@@ -24,6 +24,8 @@ pub enum Error {
     PatternUnsupported { pattern: bstr::BString },
     #[error("Both sides of the specification need a pattern, like 'a/*:b/*'")]
     PatternUnbalanced,
+    #[error("The 'tag' shorthand needs a tag name, like 'tag v1.0'")]
+    TagShorthandWithoutName,
     #[error(transparent)]
     ReferenceName(#[from] gix_validate::reference::name::Error),
     #[error(transparent)]
@@ -45,9 +47,33 @@ pub(crate) mod function {
     use crate::{
         parse::{Error, Operation},
         types::Mode,
-        RefSpecRef,
+        RefSpec, RefSpecRef,
     };
 
+    /// Like [`parse()`], but also expand shorthands that `git fetch` and `git push` accept on the command-line.
+    ///
+    /// Currently this is `tag <name>`, which is equivalent to `refs/tags/<name>:refs/tags/<name>` for either `operation`.
+    /// As it isn't forced, existing tags won't be updated if they point to a different object.
+    /// An owned spec is returned as the expansion can't refer to `spec`.
+    pub fn parse_with_shorthands(spec: &BStr, operation: Operation) -> Result<RefSpec, Error> {
+        match spec.strip_prefix(b"tag ") {
+            Some(name) => {
+                if name.is_empty() {
+                    return Err(Error::TagShorthandWithoutName);
+                }
+                let mut full_name = bstr::BString::from("refs/tags/");
+                full_name.extend_from_slice(name);
+                gix_validate::reference::name(full_name.as_ref())?;
+
+                let mut expanded = full_name.clone();
+                expanded.push(b':');
+                expanded.extend_from_slice(&full_name);
+                parse(expanded.as_ref(), operation).map(|spec| spec.to_owned())
+            }
+            None => parse(spec, operation).map(|spec| spec.to_owned()),
+        }
+    }
+
     /// Parse `spec` for use in `operation` and return it if it is valid.
     pub fn parse(mut spec: &BStr, operation: Operation) -> Result<RefSpecRef<'_>, Error> {
         fn fetch_head_only(mode: Mode) -> RefSpecRef<'static> {
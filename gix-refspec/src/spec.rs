@@ -23,6 +23,11 @@ impl RefSpec {
     pub fn allow_non_fast_forward(&self) -> bool {
         matches!(self.mode, Mode::Force)
     }
+
+    /// Return true if this spec uses a glob pattern, see [`RefSpecRef::is_pattern()`].
+    pub fn is_pattern(&self) -> bool {
+        self.to_ref().is_pattern()
+    }
 }
 
 mod impls {
@@ -122,6 +127,15 @@ impl<'a> RefSpecRef<'a> {
         }
     }
 
+    /// Return true if this spec uses a glob pattern, like `refs/heads/*:refs/remotes/origin/*`, instead of naming
+    /// a single reference.
+    ///
+    /// As [`parse()`][crate::parse()] rejects specs with a pattern on only one side, a pattern is present on all
+    /// sides of the spec if this returns `true`.
+    pub fn is_pattern(&self) -> bool {
+        self.src.map_or(false, |src| src.contains(&b'*')) || self.dst.map_or(false, |dst| dst.contains(&b'*'))
+    }
+
     /// Derive the prefix from the [`source`][Self::source()] side of this spec if this is a fetch spec,
     /// or the [`destination`][Self::destination()] side if it is a push spec, if it is possible to do so without ambiguity.
     ///
@@ -232,17 +246,20 @@ impl RefSpecRef<'_> {
     }
 }
 
+/// The prefixes to try in order when expanding a partial name, and whether `/HEAD` should be appended.
+const PARTIAL_NAME_EXPANSIONS: [(&str, bool); 6] = [
+    ("", false),
+    ("refs/", false),
+    ("refs/tags/", false),
+    ("refs/heads/", false),
+    ("refs/remotes/", false),
+    ("refs/remotes/", true),
+];
+
 pub(crate) fn expand_partial_name<T>(name: &BStr, mut cb: impl FnMut(&BStr) -> Option<T>) -> Option<T> {
     use bstr::ByteVec;
     let mut buf = BString::from(Vec::with_capacity(128));
-    for (base, append_head) in [
-        ("", false),
-        ("refs/", false),
-        ("refs/tags/", false),
-        ("refs/heads/", false),
-        ("refs/remotes/", false),
-        ("refs/remotes/", true),
-    ] {
+    for (base, append_head) in PARTIAL_NAME_EXPANSIONS {
         buf.clear();
         buf.push_str(base);
         buf.push_str(name);
@@ -255,3 +272,20 @@ pub(crate) fn expand_partial_name<T>(name: &BStr, mut cb: impl FnMut(&BStr) -> O
     }
     None
 }
+
+/// Return `true` if `full_name` is any of the names that [`expand_partial_name()`] would produce for `name`,
+/// but without allocating.
+pub(crate) fn is_expansion_of_partial_name(name: &BStr, full_name: &BStr) -> bool {
+    PARTIAL_NAME_EXPANSIONS.iter().any(|(base, append_head)| {
+        full_name
+            .strip_prefix(base.as_bytes())
+            .and_then(|rest| rest.strip_prefix(name.as_bytes()))
+            .map_or(false, |rest| {
+                if *append_head {
+                    rest == b"/HEAD"
+                } else {
+                    rest.is_empty()
+                }
+            })
+    })
+}
@@ -184,3 +184,89 @@ mod multiple {
         )
     }
 }
+
+mod deduplication {
+    use gix_refspec::{
+        match_group::{Item, SourceRef},
+        parse::Operation,
+        MatchGroup,
+    };
+
+    #[test]
+    fn overlapping_specs_yield_each_source_and_destination_once_in_spec_and_item_order() {
+        let id = gix_hash::Kind::Sha1.null();
+        let items = ["refs/heads/f1", "refs/heads/main", "refs/heads/f2"].map(|name| Item {
+            full_ref_name: name.into(),
+            target: &id,
+            object: None,
+        });
+        let specs = [
+            "refs/heads/main:refs/remotes/origin/main",
+            "+refs/heads/*:refs/remotes/origin/*",
+            "refs/heads/main:refs/remotes/origin/main",
+            "refs/heads/main",
+        ]
+        .map(|spec| gix_refspec::parse(spec.into(), Operation::Fetch).expect("valid"));
+        let out = MatchGroup::from_fetch_specs(specs).match_remotes(items.iter().copied());
+        assert_eq!(
+            out.mappings
+                .iter()
+                .map(|m| (
+                    m.spec_index,
+                    m.item_index,
+                    match m.lhs {
+                        SourceRef::FullName(name) => name.to_string(),
+                        SourceRef::ObjectId(id) => id.to_string(),
+                    },
+                    m.rhs.as_ref().map(ToString::to_string)
+                ))
+                .collect::<Vec<_>>(),
+            [
+                (0, Some(1), "refs/heads/main", Some("refs/remotes/origin/main")),
+                (1, Some(0), "refs/heads/f1", Some("refs/remotes/origin/f1")),
+                (1, Some(2), "refs/heads/f2", Some("refs/remotes/origin/f2")),
+                (3, Some(1), "refs/heads/main", None),
+            ]
+            .into_iter()
+            .map(|(spec_index, item_index, lhs, rhs)| (
+                spec_index,
+                item_index,
+                lhs.to_owned(),
+                rhs.map(ToOwned::to_owned)
+            ))
+            .collect::<Vec<_>>(),
+            "`main` is mapped only once to the same destination, but a mapping without destination is distinct"
+        );
+    }
+}
+
+mod object_ids {
+    use gix_refspec::{match_group::SourceRef, parse::Operation, MatchGroup};
+
+    use crate::matching::baseline;
+
+    #[test]
+    fn tags_and_their_peeled_objects_are_mapped_directly() {
+        let (tag, peeled) = baseline::input()
+            .find_map(|item| item.object.map(|object| (item.target.to_owned(), object.to_owned())))
+            .expect("an annotated tag in the fixture");
+        assert!(
+            baseline::input().any(|item| item.target == peeled.as_ref() && item.object.is_none()),
+            "the peeled object is also the target of a branch"
+        );
+        for id in [tag, peeled] {
+            let hex = id.to_string();
+            let spec = gix_refspec::parse(hex.as_str().into(), Operation::Fetch).expect("valid");
+            let out = MatchGroup::from_fetch_specs(Some(spec)).match_remotes(baseline::input());
+            assert_eq!(
+                out.mappings.len(),
+                1,
+                "{hex}: items pointing to the object don't add mappings"
+            );
+            let mapping = &out.mappings[0];
+            assert_eq!(mapping.item_index, None);
+            assert_eq!(mapping.lhs, SourceRef::ObjectId(id));
+            assert_eq!(mapping.rhs, None);
+        }
+    }
+}
@@ -42,7 +42,14 @@ fn complex_patterns_with_more_than_one_asterisk() {
 #[test]
 fn both_sides_need_pattern_if_one_uses_it() {
     for op in [Operation::Fetch, Operation::Push] {
-        for spec in ["refs/*/a", ":a/*", "+:a/*", "a*:b/c", "a:b/*"] {
+        for spec in [
+            "refs/*/a",
+            ":a/*",
+            "+:a/*",
+            "a*:b/c",
+            "a:b/*",
+            "refs/heads/*:refs/remotes/origin/main",
+        ] {
             assert!(
                 matches!(try_parse(spec, op).unwrap_err(), Error::PatternUnbalanced),
                 "{}",
@@ -66,3 +73,22 @@ fn fuzzed() {
     drop(gix_refspec::parse(input.into(), gix_refspec::parse::Operation::Fetch).unwrap_err());
     drop(gix_refspec::parse(input.into(), gix_refspec::parse::Operation::Push).unwrap_err());
 }
+
+#[test]
+fn tag_shorthand_without_or_with_invalid_name() {
+    for op in [Operation::Fetch, Operation::Push] {
+        assert!(matches!(
+            gix_refspec::parse_with_shorthands("tag ".into(), op).unwrap_err(),
+            Error::TagShorthandWithoutName
+        ));
+        for spec in ["tag v*", "tag a:b", "tag  v1.0", "tag v1.0/"] {
+            assert!(
+                matches!(
+                    gix_refspec::parse_with_shorthands(spec.into(), op).unwrap_err(),
+                    Error::ReferenceName(_)
+                ),
+                "{spec}: the tag name must be valid on its own"
+            );
+        }
+    }
+}
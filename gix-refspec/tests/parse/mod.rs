@@ -80,9 +80,28 @@ fn local_and_remote() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn is_pattern() -> crate::Result {
+    for (spec, op, expected) in [
+        ("refs/heads/*:refs/remotes/origin/*", Operation::Fetch, true),
+        ("+refs/heads/*:refs/remotes/origin/*", Operation::Fetch, true),
+        ("refs/heads/*:refs/heads/*", Operation::Push, true),
+        ("refs/heads/main:refs/remotes/origin/main", Operation::Fetch, false),
+        ("main", Operation::Fetch, false),
+        ("^refs/heads/main", Operation::Fetch, false),
+        (":refs/heads/main", Operation::Push, false),
+    ] {
+        let parsed = gix_refspec::parse(spec.into(), op)?;
+        assert_eq!(parsed.is_pattern(), expected, "{spec}");
+        assert_eq!(parsed.to_owned().is_pattern(), expected, "{spec}");
+    }
+    Ok(())
+}
+
 mod fetch;
 mod invalid;
 mod push;
+mod shorthands;
 
 mod util {
     use gix_refspec::{parse::Operation, Instruction, RefSpecRef};
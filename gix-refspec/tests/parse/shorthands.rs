@@ -0,0 +1,44 @@
+use gix_refspec::{
+    instruction::{Fetch, Push},
+    parse::Operation,
+    Instruction,
+};
+
+#[test]
+fn tag_expands_to_the_full_tag_refspec() -> crate::Result {
+    let spec = gix_refspec::parse_with_shorthands("tag v1.0".into(), Operation::Fetch)?;
+    assert_eq!(
+        spec.to_ref().instruction(),
+        Instruction::Fetch(Fetch::AndUpdate {
+            src: "refs/tags/v1.0".into(),
+            dst: "refs/tags/v1.0".into(),
+            allow_non_fast_forward: false,
+        }),
+        "tags aren't forced, so existing ones aren't updated"
+    );
+
+    let spec = gix_refspec::parse_with_shorthands("tag v1.0".into(), Operation::Push)?;
+    assert_eq!(
+        spec.to_ref().instruction(),
+        Instruction::Push(Push::Matching {
+            src: "refs/tags/v1.0".into(),
+            dst: "refs/tags/v1.0".into(),
+            allow_non_fast_forward: false,
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn other_specs_are_parsed_normally() -> crate::Result {
+    for op in [Operation::Fetch, Operation::Push] {
+        for spec in ["tag", "+refs/heads/main:refs/remotes/origin/main", "refs/tags/tag"] {
+            assert_eq!(
+                gix_refspec::parse_with_shorthands(spec.into(), op)?,
+                gix_refspec::parse(spec.into(), op)?.to_owned(),
+                "{spec}: 'tag' without a space is a partial ref name"
+            );
+        }
+    }
+    Ok(())
+}
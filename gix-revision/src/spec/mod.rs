@@ -20,7 +20,80 @@ pub enum Kind {
     ExcludeReachableFromParents,
 }
 
+/// Instantiation
 impl Spec {
+    /// Include `id` and all of its ancestors, like `id`.
+    pub fn include(id: impl Into<gix_hash::ObjectId>) -> Self {
+        Spec::Include(id.into())
+    }
+
+    /// Exclude `id` and all of its ancestors, like `^id`.
+    pub fn exclude(id: impl Into<gix_hash::ObjectId>) -> Self {
+        Spec::Exclude(id.into())
+    }
+
+    /// Include everything reachable from `to_inclusive`, but nothing reachable from `from_exclusive`, like `from..to`.
+    pub fn range(from_exclusive: impl Into<gix_hash::ObjectId>, to_inclusive: impl Into<gix_hash::ObjectId>) -> Self {
+        Spec::Range {
+            from: from_exclusive.into(),
+            to: to_inclusive.into(),
+        }
+    }
+
+    /// Include everything reachable from either `theirs` or `ours`, but nothing that is reachable from both,
+    /// i.e. stop at their merge-base, like `theirs...ours`.
+    pub fn reachable_to_merge_base(theirs: impl Into<gix_hash::ObjectId>, ours: impl Into<gix_hash::ObjectId>) -> Self {
+        Spec::Merge {
+            theirs: theirs.into(),
+            ours: ours.into(),
+        }
+    }
+
+    /// Include everything reachable from the parents of `of_exclusive`, but not `of_exclusive` itself, like `id^@`.
+    pub fn include_only_parents(of_exclusive: impl Into<gix_hash::ObjectId>) -> Self {
+        Spec::IncludeOnlyParents(of_exclusive.into())
+    }
+
+    /// Include `of_inclusive`, but exclude everything reachable from its parents, like `id^!`.
+    pub fn exclude_parents(of_inclusive: impl Into<gix_hash::ObjectId>) -> Self {
+        Spec::ExcludeParents(of_inclusive.into())
+    }
+}
+
+impl Kind {
+    /// Return the kind that selects the opposite side of a single revision, turning [`IncludeReachable`][Kind::IncludeReachable]
+    /// into [`ExcludeReachable`][Kind::ExcludeReachable] and vice versa.
+    ///
+    /// All other kinds return `None` as they can't be inverted without changing their meaning.
+    pub fn invert(self) -> Option<Kind> {
+        match self {
+            Kind::IncludeReachable => Some(Kind::ExcludeReachable),
+            Kind::ExcludeReachable => Some(Kind::IncludeReachable),
+            Kind::RangeBetween
+            | Kind::ReachableToMergeBase
+            | Kind::IncludeReachableFromParents
+            | Kind::ExcludeReachableFromParents => None,
+        }
+    }
+}
+
+impl Spec {
+    /// If this is a [`Merge`][Spec::Merge] spec like `theirs...ours`, return the one-sided specs to realize it given the
+    /// `merge_base` of `theirs` and `ours`, i.e. `[theirs, ours, ^merge_base]`, or `None` if this is any other kind of spec.
+    ///
+    /// This works as everything reachable from both sides is also reachable from their merge-base, which thus is the boundary
+    /// to stop at. Note that if there are multiple merge-bases, each one of them needs to be excluded.
+    pub fn expand_merge(self, merge_base: impl Into<gix_hash::ObjectId>) -> Option<[Spec; 3]> {
+        match self {
+            Spec::Merge { theirs, ours } => Some([
+                Spec::Include(theirs),
+                Spec::Include(ours),
+                Spec::Exclude(merge_base.into()),
+            ]),
+            _ => None,
+        }
+    }
+
     /// Return the kind of this specification.
     pub fn kind(&self) -> Kind {
         match self {
@@ -73,7 +146,7 @@ pub(crate) mod types {
         ///
         /// The equivalent to [crate::spec::Kind::RangeBetween], but with data.
         Range {
-            /// The starting point of the range, which is included in the set.
+            /// The starting point of the range, which is excluded from the set along with all of its ancestors.
             from: gix_hash::ObjectId,
             /// The end point of the range, which is included in the set.
             to: gix_hash::ObjectId,
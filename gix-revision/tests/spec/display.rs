@@ -63,3 +63,46 @@ fn exclude_parents() {
         "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa^!"
     );
 }
+
+#[test]
+fn constructors_are_consistent_with_kind_and_display() {
+    use gix_revision::{spec::Kind, Spec};
+    let (a, b) = (oid(), oid2());
+    for (spec, expected, kind, expected_display) in [
+        (Spec::include(a), Spec::Include(a), Kind::IncludeReachable, "aaaa"),
+        (Spec::exclude(a), Spec::Exclude(a), Kind::ExcludeReachable, "^aaaa"),
+        (
+            Spec::range(a, b),
+            Spec::Range { from: a, to: b },
+            Kind::RangeBetween,
+            "aaaa..bbbb",
+        ),
+        (
+            Spec::reachable_to_merge_base(a, b),
+            Spec::Merge { theirs: a, ours: b },
+            Kind::ReachableToMergeBase,
+            "aaaa...bbbb",
+        ),
+        (
+            Spec::include_only_parents(a),
+            Spec::IncludeOnlyParents(a),
+            Kind::IncludeReachableFromParents,
+            "aaaa^@",
+        ),
+        (
+            Spec::exclude_parents(a),
+            Spec::ExcludeParents(a),
+            Kind::ExcludeReachableFromParents,
+            "aaaa^!",
+        ),
+    ] {
+        assert_eq!(spec, expected);
+        assert_eq!(spec.kind(), kind);
+        assert_eq!(
+            spec.to_string()
+                .replace(&"a".repeat(40), "aaaa")
+                .replace(&"b".repeat(40), "bbbb"),
+            expected_display
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use gix_revision::{spec::Kind, Spec};
+
+use crate::hex_to_id;
+
+#[test]
+fn invert() {
+    assert_eq!(Kind::IncludeReachable.invert(), Some(Kind::ExcludeReachable));
+    assert_eq!(Kind::ExcludeReachable.invert(), Some(Kind::IncludeReachable));
+    for kind in [
+        Kind::RangeBetween,
+        Kind::ReachableToMergeBase,
+        Kind::IncludeReachableFromParents,
+        Kind::ExcludeReachableFromParents,
+    ] {
+        assert_eq!(kind.invert(), None, "{kind:?} can't be inverted");
+    }
+}
+
+#[test]
+fn expand_merge() {
+    let theirs = hex_to_id("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa");
+    let ours = hex_to_id("bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb");
+    let base = hex_to_id("cccccccccccccccccccccccccccccccccccccccc");
+
+    let expanded = Spec::reachable_to_merge_base(theirs, ours)
+        .expand_merge(base)
+        .expect("merge specs can be expanded");
+    assert_eq!(
+        expanded,
+        [Spec::include(theirs), Spec::include(ours), Spec::exclude(base)],
+        "both sides are included up to their merge-base"
+    );
+    assert_eq!(
+        expanded.map(|spec| spec.kind()),
+        [
+            Kind::IncludeReachable,
+            Kind::IncludeReachable,
+            Kind::IncludeReachable.invert().expect("invertible")
+        ]
+    );
+
+    for spec in [
+        Spec::include(theirs),
+        Spec::exclude(theirs),
+        Spec::range(theirs, ours),
+        Spec::include_only_parents(theirs),
+        Spec::exclude_parents(theirs),
+    ] {
+        assert_eq!(spec.expand_merge(base), None, "only merge specs are expanded");
+    }
+}
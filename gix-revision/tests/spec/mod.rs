@@ -1,2 +1,3 @@
 mod display;
+mod kind;
 mod parse;
@@ -132,6 +132,9 @@ impl Capabilities {
     }
 
     /// Returns the capability with `name`.
+    ///
+    /// In protocol V2, commands like `ls-refs` or `fetch` are advertised as capabilities whose values are the features
+    /// they support, so `capability("ls-refs")?.supports("unborn")` tells if `ls-refs` can be asked for unborn refs.
     pub fn capability(&self, name: &str) -> Option<Capability<'_>> {
         self.iter().find(|c| c.name() == name.as_bytes().as_bstr())
     }
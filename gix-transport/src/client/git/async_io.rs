@@ -130,6 +130,7 @@ mod async_net {
     impl git::Connection<TcpStream, TcpStream> {
         /// Create a new TCP connection using the `git` protocol of `desired_version`, and make a connection to `host`
         /// at `port` for accessing the repository at `path` on the server side.
+        /// `host` may be an IPv6 address in brackets, like `[::1]`, as it appears in URLs.
         /// If `trace` is `true`, all packetlines received or sent will be passed to the facilities of the `gix-trace` crate.
         pub async fn new_tcp(
             host: &str,
@@ -140,7 +141,7 @@ mod async_net {
         ) -> Result<git::Connection<TcpStream, TcpStream>, Error> {
             let read = async_std::io::timeout(
                 Duration::from_secs(5),
-                TcpStream::connect(&(host, port.unwrap_or(9418))),
+                TcpStream::connect(&(git::host::without_brackets(host), port.unwrap_or(9418))),
             )
             .await?;
             let write = read.clone();
@@ -144,7 +144,7 @@ pub mod connect {
     pub enum Error {
         #[error("An IO error occurred when connecting to the server")]
         Io(#[from] std::io::Error),
-        #[error("Could not parse {host:?} as virtual host with format <host>[:port]")]
+        #[error("Could not parse {host:?} as virtual host with format <host>[:port] or [<ipv6>][:port]")]
         VirtualHostInvalid { host: String },
     }
 
@@ -158,19 +158,15 @@ pub mod connect {
     }
 
     fn parse_host(input: String) -> Result<(String, Option<u16>), Error> {
-        let mut tokens = input.splitn(2, ':');
-        Ok(match (tokens.next(), tokens.next()) {
-            (Some(host), None) => (host.to_owned(), None),
-            (Some(host), Some(port)) => (
-                host.to_owned(),
-                Some(port.parse().map_err(|_| Error::VirtualHostInvalid { host: input })?),
-            ),
-            _ => unreachable!("we expect at least one token, the original string"),
-        })
+        match git::host::parse_with_port(&input) {
+            Some((host, port)) => Ok((host.to_owned(), port)),
+            None => Err(Error::VirtualHostInvalid { host: input }),
+        }
     }
 
     /// Connect to a git daemon running on `host` and optionally `port` and a repository at `path`.
     ///
+    /// `host` may be an IPv6 address in brackets, like `[::1]`, as it appears in URLs.
     /// Use `desired_version` to specify a preferred protocol to use, knowing that it can be downgraded by a server not supporting it.
     /// If `trace` is `true`, all packetlines received or sent will be passed to the facilities of the `gix-trace` crate.
     pub fn connect(
@@ -181,7 +177,7 @@ pub mod connect {
         trace: bool,
     ) -> Result<git::Connection<TcpStream, TcpStream>, Error> {
         let read = TcpStream::connect_timeout(
-            &(host, port.unwrap_or(9418))
+            &(git::host::without_brackets(host), port.unwrap_or(9418))
                 .to_socket_addrs()?
                 .next()
                 .expect("after successful resolution there is an IP address"),
@@ -166,6 +166,20 @@ mod message {
             )
         }
 
+        #[test]
+        fn with_ipv6_host_with_port() {
+            assert_eq!(
+                git::message::connect(
+                    Service::UploadPack,
+                    Protocol::V1,
+                    b"hello\\world",
+                    Some(&("[::1]".into(), Some(9418))),
+                    &[]
+                ),
+                "git-upload-pack hello\\world\0host=[::1]:9418\0"
+            )
+        }
+
         #[test]
         fn with_strange_host_and_port() {
             assert_eq!(
@@ -183,6 +197,74 @@ mod message {
     }
 }
 
+mod host {
+    /// Return `host` without the brackets that surround IPv6 addresses in URLs, as needed for name resolution.
+    #[cfg(any(feature = "blocking-client", feature = "async-std"))]
+    pub(crate) fn without_brackets(host: &str) -> &str {
+        host.strip_prefix('[')
+            .and_then(|host| host.strip_suffix(']'))
+            .unwrap_or(host)
+    }
+
+    /// Parse `input` as `<host>[:<port>]`, where `host` may be an IPv6 address in brackets, like `[::1]:9418`.
+    ///
+    /// The host retains its brackets, just like `git` sends it in the `host=` part of its initial request.
+    /// Return `None` if the port isn't a number or if there is anything else after the closing bracket.
+    #[cfg(feature = "blocking-client")]
+    pub(crate) fn parse_with_port(input: &str) -> Option<(&str, Option<u16>)> {
+        let (host, port) = if input.starts_with('[') {
+            let end = input.find(']')? + 1;
+            let (host, rest) = input.split_at(end);
+            match rest {
+                "" => (host, None),
+                _ => (host, Some(rest.strip_prefix(':')?)),
+            }
+        } else {
+            match input.split_once(':') {
+                Some((host, port)) => (host, Some(port)),
+                None => (input, None),
+            }
+        };
+        Some((host, port.map(str::parse).transpose().ok()?))
+    }
+
+    #[cfg(all(test, feature = "blocking-client"))]
+    mod tests {
+        use super::{parse_with_port, without_brackets};
+
+        #[test]
+        fn brackets_are_removed_only_if_balanced() {
+            assert_eq!(without_brackets("[::1]"), "::1");
+            assert_eq!(without_brackets("host"), "host");
+            assert_eq!(without_brackets("[::1"), "[::1");
+        }
+
+        #[test]
+        fn hosts_with_and_without_port() {
+            assert_eq!(parse_with_port("host"), Some(("host", None)));
+            assert_eq!(parse_with_port("host:1234"), Some(("host", Some(1234))));
+            assert_eq!(parse_with_port("[::1]"), Some(("[::1]", None)));
+            assert_eq!(parse_with_port("[::1]:9418"), Some(("[::1]", Some(9418))));
+        }
+
+        #[test]
+        fn invalid_ports_or_brackets() {
+            for input in [
+                "host:",
+                "host:port",
+                "host:1:2",
+                "[::1]:",
+                "[::1]x",
+                "[::1]:x",
+                "[::1",
+                "::1",
+            ] {
+                assert_eq!(parse_with_port(input), None, "{input}");
+            }
+        }
+    }
+}
+
 #[cfg(feature = "async-client")]
 mod async_io;
 
@@ -27,6 +27,9 @@ pub mod capabilities;
 #[doc(inline)]
 pub use capabilities::Capabilities;
 
+///
+pub mod progress;
+
 mod non_io_types;
 pub use gix_sec::identity::Account;
 pub use non_io_types::{Error, MessageKind, WriteMode};
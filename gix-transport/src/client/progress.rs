@@ -0,0 +1,72 @@
+use std::borrow::Cow;
+
+use gix_packetline::read::ProgressAction;
+
+const ESC: u8 = 0x1b;
+const BEL: u8 = 0x07;
+
+/// Remove all terminal escape sequences, like those used for coloring, from `text` as received by the remote in a
+/// progress or error message.
+///
+/// Control sequences like `ESC [ 1 ; 31 m`, operating system commands terminated by `BEL` or `ESC \`, and
+/// other two-character escapes are removed entirely, along with an incomplete escape sequence at the end of `text`.
+/// Other control characters, like the `\r` used by `git` to update progress in place, are retained.
+///
+/// No allocation is performed if `text` doesn't contain escape sequences.
+pub fn strip_ansi_escapes(text: &[u8]) -> Cow<'_, [u8]> {
+    let Some(first_escape) = text.iter().position(|b| *b == ESC) else {
+        return Cow::Borrowed(text);
+    };
+    let mut out = Vec::with_capacity(text.len());
+    out.extend_from_slice(&text[..first_escape]);
+    let mut bytes = text[first_escape..].iter().copied();
+    while let Some(b) = bytes.next() {
+        if b != ESC {
+            out.push(b);
+            continue;
+        }
+        match bytes.next() {
+            Some(b'[') => {
+                // Skip parameter and intermediate bytes until the final byte.
+                for b in bytes.by_ref() {
+                    if (0x40..=0x7e).contains(&b) {
+                        break;
+                    }
+                }
+            }
+            Some(b']') => {
+                let mut prev = 0;
+                for b in bytes.by_ref() {
+                    if b == BEL || (prev == ESC && b == b'\\') {
+                        break;
+                    }
+                    prev = b;
+                }
+            }
+            Some(b) if (0x20..=0x2f).contains(&b) => {
+                // Skip intermediate bytes along with the final byte.
+                for b in bytes.by_ref() {
+                    if !(0x20..=0x2f).contains(&b) {
+                        break;
+                    }
+                }
+            }
+            Some(_) | None => {}
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// Wrap `handle_progress(is_error, text)` so that it receives `text` with all terminal escape sequences removed,
+/// as described in [`strip_ansi_escapes()`], which is useful if progress messages of the remote are logged
+/// in non-terminal contexts.
+///
+/// Messages sent through the error band of the remote continue to be passed with `is_error` set to `true`.
+///
+/// Use it with [`ExtendedBufRead::set_progress_handler()`][crate::client::ExtendedBufRead::set_progress_handler()] like
+/// `reader.set_progress_handler(Some(Box::new(without_ansi_escapes(handle_progress))))`.
+pub fn without_ansi_escapes<'a>(
+    mut handle_progress: impl FnMut(bool, &[u8]) -> ProgressAction + 'a,
+) -> impl FnMut(bool, &[u8]) -> ProgressAction + 'a {
+    move |is_error, text| handle_progress(is_error, &strip_ansi_escapes(text))
+}
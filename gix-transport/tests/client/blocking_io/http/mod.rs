@@ -355,6 +355,45 @@ User-Agent: git/oxide-{}
     Ok(())
 }
 
+#[test]
+fn handshake_v2_downgrade_to_v1() -> crate::Result {
+    let (server, mut c) = mock::serve_and_connect(
+        "v1/http-handshake.response",
+        "path/not/important/due/to/mock",
+        Protocol::V2,
+    )?;
+    let SetServiceResponse {
+        actual_protocol,
+        capabilities,
+        refs,
+    } = c.handshake(Service::UploadPack, &[])?;
+    assert_eq!(
+        actual_protocol,
+        Protocol::V1,
+        "the server ignored our request for V2 and we detect that from the response"
+    );
+    assert!(
+        capabilities.contains("multi_ack_detailed"),
+        "capabilities are parsed in V1 format"
+    );
+    let refs = refs
+        .expect("V1 downgrades 'just happen', so we should have refs as part of the handshake")
+        .lines()
+        .map_while(Result::ok)
+        .collect::<Vec<_>>();
+    assert_eq!(refs.len(), 51);
+    assert_eq!(refs[0], "73a6868963993a3328e7d8fe94e5a6ac5078a944 HEAD");
+
+    assert!(
+        server
+            .received_as_string()
+            .to_lowercase()
+            .contains("git-protocol: version=2"),
+        "V2 was requested nonetheless"
+    );
+    Ok(())
+}
+
 #[test]
 fn clone_v1() -> crate::Result {
     let (server, mut c) = mock::serve_and_connect(
@@ -50,6 +50,30 @@ fn from_bytes() -> crate::Result {
     Ok(())
 }
 
+#[test]
+fn from_lines_v2_commands_and_their_features() -> crate::Result {
+    let caps = Capabilities::from_lines(
+        "version 2\nagent=git/2.42.0\nls-refs=unborn\nfetch=shallow wait-for-done filter\nserver-option\nobject-format=sha1\n"
+            .into(),
+    )?;
+    let ls_refs = caps.capability("ls-refs").expect("command advertised");
+    assert_eq!(ls_refs.supports("unborn"), Some(true));
+    assert_eq!(ls_refs.supports("symrefs"), Some(false), "not advertised as feature");
+
+    let fetch = caps.capability("fetch").expect("command advertised");
+    assert_eq!(
+        fetch.values().expect("features present").collect::<Vec<_>>(),
+        ["shallow", "wait-for-done", "filter"]
+    );
+    assert_eq!(fetch.supports("filter"), Some(true));
+
+    let server_option = caps.capability("server-option").expect("present");
+    assert_eq!(server_option.value(), None, "no features means no values");
+    assert_eq!(server_option.supports("anything"), None);
+    assert!(caps.capability("object-info").is_none(), "unadvertised commands");
+    Ok(())
+}
+
 #[maybe_async::test(feature = "blocking-client", async(feature = "async-client", async_std::test))]
 async fn from_lines_with_version_detection_v0() -> crate::Result {
     let mut buf = Vec::<u8>::new();
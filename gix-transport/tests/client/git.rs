@@ -238,6 +238,43 @@ async fn handshake_v1_process_mode() -> crate::Result {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "blocking-client")]
+fn connect_to_ipv6_daemon_sends_bracketed_virtual_host() -> crate::Result {
+    use std::io::Read;
+    let listener = match std::net::TcpListener::bind("[::1]:0") {
+        Ok(listener) => listener,
+        Err(err) => {
+            eprintln!("Skipping test as IPv6 loopback isn't available: {err}");
+            return Ok(());
+        }
+    };
+    let port = listener.local_addr()?.port();
+    let server = std::thread::spawn(move || -> std::io::Result<Vec<u8>> {
+        let (mut stream, _) = listener.accept()?;
+        let mut hex_len = [0; 4];
+        stream.read_exact(&mut hex_len)?;
+        let len = usize::from_str_radix(std::str::from_utf8(&hex_len).expect("ascii"), 16).expect("hex");
+        let mut request = vec![0; len - hex_len.len()];
+        stream.read_exact(&mut request)?;
+        Ok(request)
+    });
+
+    let mut c = git::connect("[::1]", "/repo.git".into(), Protocol::V1, Some(port), false)?;
+    assert!(
+        c.handshake(Service::UploadPack, &[]).is_err(),
+        "the server hangs up after receiving the request"
+    );
+    assert_eq!(
+        server.join().expect("no panic")?.as_bstr(),
+        format!("git-upload-pack /repo.git\0host=[::1]:{port}\0")
+            .as_bytes()
+            .as_bstr(),
+        "the address is resolved without brackets, but the virtual host keeps them just like git does"
+    );
+    Ok(())
+}
+
 #[maybe_async::test(feature = "blocking-client", async(feature = "async-client", async_std::test))]
 async fn handshake_v2_downgrade_to_v1() -> crate::Result {
     let mut out = Vec::new();
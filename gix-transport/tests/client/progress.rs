@@ -0,0 +1,50 @@
+use gix_transport::client::progress::{strip_ansi_escapes, without_ansi_escapes};
+use gix_transport::packetline::read::ProgressAction;
+
+fn strip(text: &str) -> String {
+    String::from_utf8(strip_ansi_escapes(text.as_bytes()).into_owned()).expect("valid UTF-8")
+}
+
+#[test]
+fn text_without_escapes_is_borrowed() {
+    let text = b"Counting objects: 100% (3/3), done.\r";
+    assert!(matches!(strip_ansi_escapes(text), std::borrow::Cow::Borrowed(_)));
+}
+
+#[test]
+fn control_sequences_are_removed() {
+    assert_eq!(strip("\x1b[1;31merror:\x1b[m bad"), "error: bad");
+    assert_eq!(strip("\x1b[Kremote: done\r"), "remote: done\r", "erase-line is removed");
+    assert_eq!(strip("a\x1b[38;5;196mb\x1b[0mc"), "abc");
+}
+
+#[test]
+fn other_escape_sequences_are_removed() {
+    assert_eq!(strip("\x1b]0;title\x07text"), "text", "OSC terminated with BEL");
+    assert_eq!(
+        strip("\x1b]8;;https://example.com\x1b\\link"),
+        "link",
+        "OSC terminated with ST"
+    );
+    assert_eq!(strip("\x1b(Btext\x1bc"), "text", "charset selection and reset");
+}
+
+#[test]
+fn incomplete_sequences_at_the_end_are_removed() {
+    assert_eq!(strip("text\x1b"), "text");
+    assert_eq!(strip("text\x1b[1;3"), "text");
+}
+
+#[test]
+fn wrapped_handlers_see_stripped_text_and_error_state() {
+    let mut seen = Vec::new();
+    {
+        let mut handle = without_ansi_escapes(|is_error, text: &[u8]| {
+            seen.push((is_error, String::from_utf8(text.to_owned()).expect("valid UTF-8")));
+            ProgressAction::Continue
+        });
+        assert_eq!(handle(false, b"\x1b[32mCounting\x1b[m"), ProgressAction::Continue);
+        assert_eq!(handle(true, b"\x1b[31mfatal\x1b[m"), ProgressAction::Continue);
+    }
+    assert_eq!(seen, vec![(false, "Counting".into()), (true, "fatal".into())]);
+}
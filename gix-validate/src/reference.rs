@@ -18,6 +18,19 @@ pub mod name {
         SingleDot,
     }
 
+    /// Options to control the validation performed by [`name_with_options()`][super::name_with_options()].
+    ///
+    /// They correspond to the flags of `git check-ref-format`.
+    #[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct Options {
+        /// If `true`, names without a slash like `main` are allowed, like `--allow-onelevel` does.
+        /// Otherwise such names must be all uppercase, like `HEAD` or `FETCH_HEAD`.
+        pub allow_onelevel: bool,
+        /// If `true`, allow a single `*` anywhere in the name, as is needed for refspec patterns like `refs/heads/*`,
+        /// like `--refspec-pattern` does.
+        pub refspec_pattern: bool,
+    }
+
     impl From<Infallible> for Error {
         fn from(_: Infallible) -> Self {
             unreachable!("this impl is needed to allow passing a known valid partial path as parameter")
@@ -30,13 +43,29 @@ use bstr::BStr;
 /// Validate a reference name running all the tests in the book. This disallows lower-case references, but allows
 /// ones like `HEAD`.
 pub fn name(path: &BStr) -> Result<&BStr, name::Error> {
-    validate(path, Mode::Complete)
+    validate(path, Mode::Complete, false)
 }
 
 /// Validate a partial reference name. As it is assumed to be partial, names like `some-name` is allowed
 /// even though these would be disallowed with when using [`name()`].
 pub fn name_partial(path: &BStr) -> Result<&BStr, name::Error> {
-    validate(path, Mode::Partial)
+    validate(path, Mode::Partial, false)
+}
+
+/// Validate a reference name according to `options`.
+///
+/// With default `options` this is the same as [`name()`], and with [`allow_onelevel`][name::Options::allow_onelevel]
+/// set it is the same as [`name_partial()`].
+pub fn name_with_options(path: &BStr, options: name::Options) -> Result<&BStr, name::Error> {
+    validate(
+        path,
+        if options.allow_onelevel {
+            Mode::Partial
+        } else {
+            Mode::Complete
+        },
+        options.refspec_pattern,
+    )
 }
 
 enum Mode {
@@ -44,8 +73,8 @@ enum Mode {
     Partial,
 }
 
-fn validate(path: &BStr, mode: Mode) -> Result<&BStr, name::Error> {
-    crate::tag::name(path)?;
+fn validate(path: &BStr, mode: Mode, allow_one_asterisk: bool) -> Result<&BStr, name::Error> {
+    crate::tag::validate(path, allow_one_asterisk)?;
     if path[0] == b'/' {
         return Err(name::Error::StartsWithSlash);
     }
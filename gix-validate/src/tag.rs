@@ -29,6 +29,11 @@ pub mod name {
 
 /// Assure the given `input` resemble a valid git tag name, which is returned unchanged on success.
 pub fn name(input: &BStr) -> Result<&BStr, name::Error> {
+    validate(input, false)
+}
+
+/// Like [`name()`], but allow a single `*` if `allow_one_asterisk` is `true`, as used in refspec patterns.
+pub(crate) fn validate(input: &BStr, mut allow_one_asterisk: bool) -> Result<&BStr, name::Error> {
     if input.is_empty() {
         return Err(name::Error::Empty);
     }
@@ -44,6 +49,7 @@ pub fn name(input: &BStr) -> Result<&BStr, name::Error> {
                     byte: (&[*byte][..]).into(),
                 })
             }
+            b'*' if allow_one_asterisk => allow_one_asterisk = false,
             b'*' => return Err(name::Error::Asterisk),
             b'.' if previous == b'.' => return Err(name::Error::DoubleDot),
             b'{' if previous == b'@' => return Err(name::Error::ReflogPortion),
@@ -158,3 +158,77 @@ mod name {
         );
     }
 }
+
+mod name_with_options {
+    use bstr::ByteSlice;
+    use gix_validate::{
+        reference::{
+            name::{Error as RefError, Options},
+            name_with_options,
+        },
+        tag::name::Error as TagError,
+    };
+
+    #[test]
+    fn defaults_are_like_name() {
+        let opts = Options::default();
+        assert!(name_with_options(b"refs/heads/main".as_bstr(), opts).is_ok());
+        assert!(name_with_options(b"HEAD".as_bstr(), opts).is_ok());
+        assert!(matches!(
+            name_with_options(b"main".as_bstr(), opts),
+            Err(RefError::SomeLowercase)
+        ));
+        assert!(matches!(
+            name_with_options(b"refs/heads/*".as_bstr(), opts),
+            Err(RefError::Tag(TagError::Asterisk))
+        ));
+    }
+
+    #[test]
+    fn allow_onelevel() {
+        let opts = Options {
+            allow_onelevel: true,
+            ..Default::default()
+        };
+        assert!(name_with_options(b"main".as_bstr(), opts).is_ok());
+        assert!(name_with_options(b"refs/heads/main".as_bstr(), opts).is_ok());
+        assert!(matches!(
+            name_with_options(b"main.lock".as_bstr(), opts),
+            Err(RefError::Tag(TagError::LockFileSuffix))
+        ));
+    }
+
+    #[test]
+    fn refspec_pattern() {
+        let opts = Options {
+            refspec_pattern: true,
+            ..Default::default()
+        };
+        for valid in ["refs/heads/*", "refs/heads/feature-*", "refs/*/main"] {
+            assert!(name_with_options(valid.into(), opts).is_ok(), "{valid}");
+        }
+        assert!(
+            matches!(
+                name_with_options(b"refs/*/*".as_bstr(), opts),
+                Err(RefError::Tag(TagError::Asterisk))
+            ),
+            "only a single asterisk is allowed"
+        );
+        assert!(
+            matches!(name_with_options(b"*".as_bstr(), opts), Err(RefError::SomeLowercase)),
+            "one-level names are still subject to their own rules"
+        );
+        assert!(name_with_options(
+            b"*".as_bstr(),
+            Options {
+                allow_onelevel: true,
+                refspec_pattern: true
+            }
+        )
+        .is_ok());
+        assert!(matches!(
+            name_with_options(b"refs/heads/*..".as_bstr(), opts),
+            Err(RefError::Tag(TagError::DoubleDot))
+        ));
+    }
+}